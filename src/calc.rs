@@ -0,0 +1,51 @@
+use crate::*;
+pub const PRICE_DENOMINATOR: u64 = 0x1_0000_0000;
+pub fn proportional(
+    amount: u64,
+    numerator: u64,
+    denominator: u64,
+) -> Result<u64, MarinadeFinanceError> {
+    if denominator == 0 {
+        return Ok(amount);
+    }
+    u64::try_from(amount as u128 * numerator as u128 / denominator as u128)
+        .map_err(|_| MarinadeFinanceError::CalculationFailure)
+}
+pub fn value_from_shares(
+    shares: u64,
+    total_value: u64,
+    total_shares: u64,
+) -> Result<u64, MarinadeFinanceError> {
+    proportional(shares, total_value, total_shares)
+}
+pub fn shares_from_value(
+    value: u64,
+    total_value: u64,
+    total_shares: u64,
+) -> Result<u64, MarinadeFinanceError> {
+    if total_shares == 0 {
+        Ok(value)
+    } else {
+        proportional(value, total_shares, total_value)
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn proportional_rounds_down() {
+        assert_eq!(proportional(10, 2, 3).unwrap(), 6);
+        assert_eq!(proportional(u64::MAX, 3, 4).unwrap(), u64::MAX / 4 * 3 + 2);
+        assert_eq!(proportional(7, 1, 0).unwrap(), 7);
+        assert_eq!(
+            proportional(u64::MAX, 2, 1).unwrap_err(),
+            MarinadeFinanceError::CalculationFailure
+        );
+    }
+    #[test]
+    fn shares_from_value_with_no_shares() {
+        assert_eq!(shares_from_value(42, 1_000, 0).unwrap(), 42);
+        assert_eq!(shares_from_value(42, 1_000, 500).unwrap(), 21);
+        assert_eq!(value_from_shares(21, 1_000, 500).unwrap(), 42);
+    }
+}
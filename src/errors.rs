@@ -1,7 +1,9 @@
 use solana_program::{
     decode_error::DecodeError,
+    instruction::{CompiledInstruction, Instruction, InstructionError},
     msg,
     program_error::{PrintProgramError, ProgramError},
+    pubkey::Pubkey,
 };
 use thiserror::Error;
 #[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
@@ -205,3 +207,496 @@ impl PrintProgramError for MarinadeFinanceError {
         msg!(&self.to_string());
     }
 }
+impl TryFrom<ProgramError> for MarinadeFinanceError {
+    type Error = ProgramError;
+    fn try_from(e: ProgramError) -> Result<Self, Self::Error> {
+        match e {
+            ProgramError::Custom(code) => Self::from_code(code).ok_or(e),
+            _ => Err(e),
+        }
+    }
+}
+impl MarinadeFinanceError {
+    pub fn from_code(code: u32) -> Option<Self> {
+        num_traits::FromPrimitive::from_u32(code)
+    }
+    pub fn from_instruction_error(program_id: &Pubkey, err: &InstructionError) -> Option<Self> {
+        MarinadeFinanceProgramError::from_instruction_error(program_id, err)
+            .and_then(MarinadeFinanceProgramError::marinade)
+    }
+    pub fn from_failed_instruction(
+        instruction_index: u8,
+        err: &InstructionError,
+        instructions: &[Instruction],
+    ) -> Option<Self> {
+        MarinadeFinanceProgramError::from_failed_instruction(instruction_index, err, instructions)
+            .and_then(MarinadeFinanceProgramError::marinade)
+    }
+    pub fn from_failed_compiled_instruction(
+        instruction_index: u8,
+        err: &InstructionError,
+        instructions: &[CompiledInstruction],
+        account_keys: &[Pubkey],
+    ) -> Option<Self> {
+        MarinadeFinanceProgramError::from_failed_compiled_instruction(
+            instruction_index,
+            err,
+            instructions,
+            account_keys,
+        )
+        .and_then(MarinadeFinanceProgramError::marinade)
+    }
+}
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum ErrorCategory {
+    UserFixable,
+    Retryable,
+    Operator,
+    Fatal,
+}
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum ErrorSeverity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+impl ErrorCategory {
+    pub fn severity(&self) -> ErrorSeverity {
+        match self {
+            Self::UserFixable => ErrorSeverity::Low,
+            Self::Retryable => ErrorSeverity::Medium,
+            Self::Operator | Self::Fatal => ErrorSeverity::High,
+        }
+    }
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Self::Retryable)
+    }
+    pub fn suggested_action(&self) -> &'static str {
+        match self {
+            Self::UserFixable => "Adjust the request and submit it again",
+            Self::Retryable => "Wait for the on-chain state to change and retry",
+            Self::Operator => "Check the program configuration or the admin accounts",
+            Self::Fatal => "Do not retry; the transaction was built incorrectly",
+        }
+    }
+}
+impl MarinadeFinanceError {
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            Self::StakeAccountWithLockup
+            | Self::WrongBeneficiary
+            | Self::StakeNotDelegated
+            | Self::StakeAccountIsEmergencyUnstaking
+            | Self::InsufficientLiquidity
+            | Self::StakingIsCapped
+            | Self::LiquidityIsCapped
+            | Self::WithdrawAmountIsTooLow
+            | Self::DepositAmountIsTooLow
+            | Self::NotEnoughUserFunds
+            | Self::WrongTokenOwnerOrDelegate
+            | Self::DepositingNotActivatedStake
+            | Self::TooLowDelegationInDepositingStake
+            | Self::WrongStakeBalance
+            | Self::InvalidDelayedUnstakeTicket
+            | Self::ReusingDelayedUnstakeTicket
+            | Self::RedepositingMarinadeStake
+            | Self::SelectedStakeAccountHasNotEnoughFunds
+            | Self::WithdrawStakeAccountIsNotEnabled
+            | Self::WithdrawStakeLamportsIsTooLow
+            | Self::StakeAccountRemainderTooLow => ErrorCategory::UserFixable,
+            Self::TicketNotDue
+            | Self::TicketNotReady
+            | Self::StakeAccountNotUpdatedYet
+            | Self::TooEarlyForStakeDelta
+            | Self::RequiredDelegatedStake
+            | Self::RequiredActiveStake
+            | Self::RequiredDeactivatingStake
+            | Self::WrongValidatorAccountOrIndex
+            | Self::WrongStakeAccountOrIndex
+            | Self::UnstakingOnPositiveDelta
+            | Self::StakingOnNegativeDelta
+            | Self::MovingStakeIsCapped
+            | Self::DestinationStakeMustBeUpdated
+            | Self::SourceStakeMustBeUpdated => ErrorCategory::Retryable,
+            Self::WrongReserveOwner
+            | Self::NonEmptyReserveData
+            | Self::InvalidInitialReserveLamports
+            | Self::ZeroValidatorChunkSize
+            | Self::TooBigValidatorChunkSize
+            | Self::ZeroCreditChunkSize
+            | Self::TooBigCreditChunkSize
+            | Self::TooLowCreditFee
+            | Self::InvalidMintAuthority
+            | Self::MintHasInitialSupply
+            | Self::InvalidOwnerFeeState
+            | Self::MinStakeIsTooLow
+            | Self::LpMaxFeeIsTooHigh
+            | Self::BasisPointsOverflow
+            | Self::LpFeesAreWrongWayRound
+            | Self::LiquidityTargetTooLow
+            | Self::InvalidAdminAuthority
+            | Self::InvalidValidatorManager
+            | Self::TreasuryCutIsTooHigh
+            | Self::RewardsFeeIsTooHigh
+            | Self::UpdateWindowIsTooLow
+            | Self::MinWithdrawIsTooHigh
+            | Self::ProgramIsPaused
+            | Self::EmergencyUnstakingFromNonZeroScoredValidator
+            | Self::WrongValidatorDuplicationFlag
+            | Self::RemovingValidatorWithBalance
+            | Self::RedelegateOverTarget
+            | Self::SourceAndDestValidatorsAreTheSame
+            | Self::ListOverflow
+            | Self::AlreadyPaused
+            | Self::NotPaused
+            | Self::InvalidPauseAuthority
+            | Self::BasisPointCentsOverflow
+            | Self::WithdrawStakeAccountFeeIsTooHigh
+            | Self::DelayedUnstakeFeeIsTooHigh
+            | Self::ShrinkingListWithDeletingContents => ErrorCategory::Operator,
+            Self::InvalidProgramId
+            | Self::UnexpectedAccount
+            | Self::CalculationFailure
+            | Self::NotUsed6027
+            | Self::InvalidStakeListDiscriminator
+            | Self::InvalidValidatorListDiscriminator
+            | Self::StakeMustBeUninitialized
+            | Self::DestinationStakeMustBeDelegated
+            | Self::DestinationStakeMustNotBeDeactivating
+            | Self::InvalidDestinationStakeDelegation
+            | Self::SourceStakeMustBeDelegated
+            | Self::SourceStakeMustNotBeDeactivating
+            | Self::InvalidSourceStakeDelegation
+            | Self::UnregisteredMsolMinted
+            | Self::UnregisteredLpMinted
+            | Self::ListIndexOutOfBounds => ErrorCategory::Fatal,
+        }
+    }
+    pub fn severity(&self) -> ErrorSeverity {
+        match self {
+            Self::ProgramIsPaused | Self::UnregisteredMsolMinted | Self::UnregisteredLpMinted => {
+                ErrorSeverity::Critical
+            }
+            _ => self.category().severity(),
+        }
+    }
+    pub fn is_retryable(&self) -> bool {
+        self.category().is_retryable()
+    }
+    pub fn suggested_action(&self) -> &'static str {
+        match self {
+            Self::TicketNotDue => "Wait for the ticket's unstake epoch to pass and claim again",
+            Self::TicketNotReady => "Wait a few hours after the epoch change and claim again",
+            Self::StakeAccountNotUpdatedYet
+            | Self::DestinationStakeMustBeUpdated
+            | Self::SourceStakeMustBeUpdated => {
+                "Wait for the stake accounts to be updated this epoch and retry"
+            }
+            Self::WrongValidatorAccountOrIndex | Self::WrongStakeAccountOrIndex => {
+                "Reload the validator and stake lists and rebuild the transaction"
+            }
+            Self::ProgramIsPaused => "Marinade is paused; retry once it is resumed",
+            Self::InsufficientLiquidity | Self::LiquidityIsCapped => {
+                "Use a smaller amount or a delayed unstake"
+            }
+            Self::StakingIsCapped => "Use a smaller amount or buy mSOL from the liquidity pool",
+            Self::DepositAmountIsTooLow | Self::WithdrawAmountIsTooLow => {
+                "Increase the amount above the program minimum"
+            }
+            Self::NotEnoughUserFunds => "Top up the source account or use a smaller amount",
+            Self::WithdrawStakeAccountIsNotEnabled => {
+                "Use liquid unstake or a delayed unstake instead"
+            }
+            _ => self.category().suggested_action(),
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, Eq, Error, num_derive::FromPrimitive, PartialEq)]
+pub enum AnchorFrameworkError {
+    #[error("8 byte instruction identifier not provided")]
+    InstructionMissing = 100,
+    #[error("Fallback functions are not supported")]
+    InstructionFallbackNotFound = 101,
+    #[error("The program could not deserialize the given instruction")]
+    InstructionDidNotDeserialize = 102,
+    #[error("The program could not serialize the given instruction")]
+    InstructionDidNotSerialize = 103,
+    #[error("The program was compiled without idl instructions")]
+    IdlInstructionStub = 1000,
+    #[error("Invalid program given to the IDL instruction")]
+    IdlInstructionInvalidProgram = 1001,
+    #[error("The program was compiled without `event-cpi` feature")]
+    EventInstructionStub = 1500,
+    #[error("A mut constraint was violated")]
+    ConstraintMut = 2000,
+    #[error("A has one constraint was violated")]
+    ConstraintHasOne = 2001,
+    #[error("A signer constraint was violated")]
+    ConstraintSigner = 2002,
+    #[error("A raw constraint was violated")]
+    ConstraintRaw = 2003,
+    #[error("An owner constraint was violated")]
+    ConstraintOwner = 2004,
+    #[error("A rent exemption constraint was violated")]
+    ConstraintRentExempt = 2005,
+    #[error("A seeds constraint was violated")]
+    ConstraintSeeds = 2006,
+    #[error("An executable constraint was violated")]
+    ConstraintExecutable = 2007,
+    #[error("Deprecated Error, feel free to replace with something else")]
+    ConstraintState = 2008,
+    #[error("An associated constraint was violated")]
+    ConstraintAssociated = 2009,
+    #[error("An associated init constraint was violated")]
+    ConstraintAssociatedInit = 2010,
+    #[error("A close constraint was violated")]
+    ConstraintClose = 2011,
+    #[error("An address constraint was violated")]
+    ConstraintAddress = 2012,
+    #[error("Expected zero account discriminant")]
+    ConstraintZero = 2013,
+    #[error("A token mint constraint was violated")]
+    ConstraintTokenMint = 2014,
+    #[error("A token owner constraint was violated")]
+    ConstraintTokenOwner = 2015,
+    #[error("A mint mint authority constraint was violated")]
+    ConstraintMintMintAuthority = 2016,
+    #[error("A mint freeze authority constraint was violated")]
+    ConstraintMintFreezeAuthority = 2017,
+    #[error("A mint decimals constraint was violated")]
+    ConstraintMintDecimals = 2018,
+    #[error("A space constraint was violated")]
+    ConstraintSpace = 2019,
+    #[error("A required account for the constraint is None")]
+    ConstraintAccountIsNone = 2020,
+    #[error("A require expression was violated")]
+    RequireViolated = 2500,
+    #[error("A require_eq expression was violated")]
+    RequireEqViolated = 2501,
+    #[error("A require_keys_eq expression was violated")]
+    RequireKeysEqViolated = 2502,
+    #[error("A require_neq expression was violated")]
+    RequireNeqViolated = 2503,
+    #[error("A require_keys_neq expression was violated")]
+    RequireKeysNeqViolated = 2504,
+    #[error("A require_gt expression was violated")]
+    RequireGtViolated = 2505,
+    #[error("A require_gte expression was violated")]
+    RequireGteViolated = 2506,
+    #[error("The account discriminator was already set on this account")]
+    AccountDiscriminatorAlreadySet = 3000,
+    #[error("No 8 byte discriminator was found on the account")]
+    AccountDiscriminatorNotFound = 3001,
+    #[error("8 byte discriminator did not match what was expected")]
+    AccountDiscriminatorMismatch = 3002,
+    #[error("Failed to deserialize the account")]
+    AccountDidNotDeserialize = 3003,
+    #[error("Failed to serialize the account")]
+    AccountDidNotSerialize = 3004,
+    #[error("Not enough account keys given to the instruction")]
+    AccountNotEnoughKeys = 3005,
+    #[error("The given account is not mutable")]
+    AccountNotMutable = 3006,
+    #[error("The given account is owned by a different program than expected")]
+    AccountOwnedByWrongProgram = 3007,
+    #[error("Program ID was not as expected")]
+    InvalidProgramId = 3008,
+    #[error("Program account is not executable")]
+    InvalidProgramExecutable = 3009,
+    #[error("The given account did not sign")]
+    AccountNotSigner = 3010,
+    #[error("The given account is not owned by the system program")]
+    AccountNotSystemOwned = 3011,
+    #[error("The program expected this account to be already initialized")]
+    AccountNotInitialized = 3012,
+    #[error("The given account is not a program data account")]
+    AccountNotProgramData = 3013,
+    #[error("The given account is not the associated token account")]
+    AccountNotAssociatedTokenAccount = 3014,
+    #[error("The given public key does not match the required sysvar")]
+    AccountSysvarMismatch = 3015,
+    #[error("The account reallocation exceeds the MAX_PERMITTED_DATA_INCREASE limit")]
+    AccountReallocExceedsLimit = 3016,
+    #[error("The account was duplicated for more than one reallocation")]
+    AccountDuplicateReallocs = 3017,
+    #[error("The declared program id does not match the actual program id")]
+    DeclaredProgramIdMismatch = 4100,
+    #[error("The API being used is deprecated and should no longer be used")]
+    Deprecated = 5000,
+}
+impl From<AnchorFrameworkError> for ProgramError {
+    fn from(e: AnchorFrameworkError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
+impl AnchorFrameworkError {
+    pub fn from_code(code: u32) -> Option<Self> {
+        num_traits::FromPrimitive::from_u32(code)
+    }
+}
+#[derive(Clone, Copy, Debug, Eq, Error, PartialEq)]
+pub enum MarinadeFinanceProgramError {
+    #[error(transparent)]
+    Anchor(#[from] AnchorFrameworkError),
+    #[error(transparent)]
+    Marinade(#[from] MarinadeFinanceError),
+}
+impl From<MarinadeFinanceProgramError> for ProgramError {
+    fn from(e: MarinadeFinanceProgramError) -> Self {
+        ProgramError::Custom(e.code())
+    }
+}
+impl TryFrom<ProgramError> for MarinadeFinanceProgramError {
+    type Error = ProgramError;
+    fn try_from(e: ProgramError) -> Result<Self, Self::Error> {
+        match e {
+            ProgramError::Custom(code) => Self::from_code(code).ok_or(e),
+            _ => Err(e),
+        }
+    }
+}
+impl MarinadeFinanceProgramError {
+    pub fn from_code(code: u32) -> Option<Self> {
+        MarinadeFinanceError::from_code(code)
+            .map(Self::Marinade)
+            .or_else(|| AnchorFrameworkError::from_code(code).map(Self::Anchor))
+    }
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::Anchor(e) => *e as u32,
+            Self::Marinade(e) => *e as u32,
+        }
+    }
+    pub fn marinade(self) -> Option<MarinadeFinanceError> {
+        match self {
+            Self::Marinade(e) => Some(e),
+            Self::Anchor(_) => None,
+        }
+    }
+    pub fn from_instruction_error(program_id: &Pubkey, err: &InstructionError) -> Option<Self> {
+        match err {
+            InstructionError::Custom(code) if *program_id == crate::ID => Self::from_code(*code),
+            _ => None,
+        }
+    }
+    pub fn from_failed_instruction(
+        instruction_index: u8,
+        err: &InstructionError,
+        instructions: &[Instruction],
+    ) -> Option<Self> {
+        let ix = instructions.get(instruction_index as usize)?;
+        Self::from_instruction_error(&ix.program_id, err)
+    }
+    pub fn from_failed_compiled_instruction(
+        instruction_index: u8,
+        err: &InstructionError,
+        instructions: &[CompiledInstruction],
+        account_keys: &[Pubkey],
+    ) -> Option<Self> {
+        let ix = instructions.get(instruction_index as usize)?;
+        let program_id = account_keys.get(ix.program_id_index as usize)?;
+        Self::from_instruction_error(program_id, err)
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn every_code_is_classified() {
+        let errors: Vec<MarinadeFinanceError> = (6000..=6086)
+            .map(|code| MarinadeFinanceError::from_code(code).unwrap())
+            .collect();
+        assert_eq!(errors.len(), 87);
+        for (code, error) in (6000..).zip(&errors) {
+            assert_eq!(*error as u32, code);
+            assert!(error.category().severity() <= error.severity());
+            assert!(!error.suggested_action().is_empty(), "{:?}", error);
+        }
+        assert_eq!(MarinadeFinanceError::from_code(5999), None);
+        assert_eq!(MarinadeFinanceError::from_code(6087), None);
+    }
+    #[test]
+    fn request_examples() {
+        use MarinadeFinanceError::*;
+        assert_eq!(TicketNotReady.category(), ErrorCategory::Retryable);
+        assert_eq!(
+            StakeAccountNotUpdatedYet.category(),
+            ErrorCategory::Retryable
+        );
+        assert_eq!(DepositAmountIsTooLow.category(), ErrorCategory::UserFixable);
+        assert_eq!(ProgramIsPaused.category(), ErrorCategory::Operator);
+        assert_eq!(ProgramIsPaused.severity(), ErrorSeverity::Critical);
+        assert_eq!(InvalidAdminAuthority.category(), ErrorCategory::Operator);
+        assert_eq!(InvalidAdminAuthority.severity(), ErrorSeverity::High);
+        assert!(TicketNotReady.is_retryable());
+        assert!(!ProgramIsPaused.is_retryable());
+    }
+    #[test]
+    fn from_failed_instruction() {
+        let other = Pubkey::new_unique();
+        let instructions = [
+            Instruction::new_with_bytes(other, &[], vec![]),
+            Instruction::new_with_bytes(crate::ID, &[], vec![]),
+        ];
+        let not_enough_funds = InstructionError::Custom(6040);
+        let account_not_initialized = InstructionError::Custom(3012);
+        assert_eq!(
+            MarinadeFinanceError::from_failed_instruction(1, &not_enough_funds, &instructions),
+            Some(MarinadeFinanceError::NotEnoughUserFunds)
+        );
+        assert_eq!(
+            MarinadeFinanceError::from_failed_instruction(0, &not_enough_funds, &instructions),
+            None
+        );
+        assert_eq!(
+            MarinadeFinanceError::from_failed_instruction(2, &not_enough_funds, &instructions),
+            None
+        );
+        assert_eq!(
+            MarinadeFinanceProgramError::from_failed_instruction(
+                1,
+                &account_not_initialized,
+                &instructions
+            ),
+            Some(MarinadeFinanceProgramError::Anchor(
+                AnchorFrameworkError::AccountNotInitialized
+            ))
+        );
+        assert_eq!(
+            MarinadeFinanceError::from_failed_instruction(
+                1,
+                &account_not_initialized,
+                &instructions
+            ),
+            None
+        );
+        assert_eq!(
+            MarinadeFinanceError::from_instruction_error(
+                &crate::ID,
+                &InstructionError::MissingAccount
+            ),
+            None
+        );
+        let account_keys = [other, crate::ID];
+        let compiled = [CompiledInstruction::new_from_raw_parts(1, vec![], vec![])];
+        assert_eq!(
+            MarinadeFinanceError::from_failed_compiled_instruction(
+                0,
+                &not_enough_funds,
+                &compiled,
+                &account_keys
+            ),
+            Some(MarinadeFinanceError::NotEnoughUserFunds)
+        );
+        assert_eq!(
+            MarinadeFinanceError::from_failed_compiled_instruction(
+                0,
+                &not_enough_funds,
+                &compiled,
+                &account_keys[..1]
+            ),
+            None
+        );
+    }
+}
@@ -3,15 +3,18 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
 pub const CHANGE_AUTHORITY_EVENT_EVENT_DISCM: [u8; 8] = [228, 111, 35, 24, 187, 78, 224, 138];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChangeAuthorityEvent {
-    state: Pubkey,
-    admin_change: Option<PubkeyValueChange>,
-    validator_manager_change: Option<PubkeyValueChange>,
-    operational_sol_account_change: Option<PubkeyValueChange>,
-    treasury_msol_account_change: Option<PubkeyValueChange>,
-    pause_authority_change: Option<PubkeyValueChange>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub state: Pubkey,
+    pub admin_change: Option<PubkeyValueChange>,
+    pub validator_manager_change: Option<PubkeyValueChange>,
+    pub operational_sol_account_change: Option<PubkeyValueChange>,
+    pub treasury_msol_account_change: Option<PubkeyValueChange>,
+    pub pause_authority_change: Option<PubkeyValueChange>,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChangeAuthorityEventEvent(pub ChangeAuthorityEvent);
 impl BorshSerialize for ChangeAuthorityEventEvent {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
@@ -36,14 +39,17 @@ impl ChangeAuthorityEventEvent {
 }
 pub const CONFIG_LP_EVENT_EVENT_DISCM: [u8; 8] = [159, 204, 192, 138, 68, 145, 224, 148];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConfigLpEvent {
-    state: Pubkey,
-    min_fee_change: Option<FeeValueChange>,
-    max_fee_change: Option<FeeValueChange>,
-    liquidity_target_change: Option<U64ValueChange>,
-    treasury_cut_change: Option<FeeValueChange>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub state: Pubkey,
+    pub min_fee_change: Option<FeeValueChange>,
+    pub max_fee_change: Option<FeeValueChange>,
+    pub liquidity_target_change: Option<U64ValueChange>,
+    pub treasury_cut_change: Option<FeeValueChange>,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConfigLpEventEvent(pub ConfigLpEvent);
 impl BorshSerialize for ConfigLpEventEvent {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
@@ -68,21 +74,24 @@ impl ConfigLpEventEvent {
 }
 pub const CONFIG_MARINADE_EVENT_EVENT_DISCM: [u8; 8] = [159, 164, 245, 114, 94, 253, 3, 9];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConfigMarinadeEvent {
-    state: Pubkey,
-    rewards_fee_change: Option<FeeValueChange>,
-    slots_for_stake_delta_change: Option<U64ValueChange>,
-    min_stake_change: Option<U64ValueChange>,
-    min_deposit_change: Option<U64ValueChange>,
-    min_withdraw_change: Option<U64ValueChange>,
-    staking_sol_cap_change: Option<U64ValueChange>,
-    liquidity_sol_cap_change: Option<U64ValueChange>,
-    withdraw_stake_account_enabled_change: Option<BoolValueChange>,
-    delayed_unstake_fee_change: Option<FeeCentsValueChange>,
-    withdraw_stake_account_fee_change: Option<FeeCentsValueChange>,
-    max_stake_moved_per_epoch_change: Option<FeeValueChange>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub state: Pubkey,
+    pub rewards_fee_change: Option<FeeValueChange>,
+    pub slots_for_stake_delta_change: Option<U64ValueChange>,
+    pub min_stake_change: Option<U64ValueChange>,
+    pub min_deposit_change: Option<U64ValueChange>,
+    pub min_withdraw_change: Option<U64ValueChange>,
+    pub staking_sol_cap_change: Option<U64ValueChange>,
+    pub liquidity_sol_cap_change: Option<U64ValueChange>,
+    pub withdraw_stake_account_enabled_change: Option<BoolValueChange>,
+    pub delayed_unstake_fee_change: Option<FeeCentsValueChange>,
+    pub withdraw_stake_account_fee_change: Option<FeeCentsValueChange>,
+    pub max_stake_moved_per_epoch_change: Option<FeeValueChange>,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConfigMarinadeEventEvent(pub ConfigMarinadeEvent);
 impl BorshSerialize for ConfigMarinadeEventEvent {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
@@ -107,18 +116,29 @@ impl ConfigMarinadeEventEvent {
 }
 pub const INITIALIZE_EVENT_EVENT_DISCM: [u8; 8] = [206, 175, 169, 208, 241, 210, 35, 221];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitializeEvent {
-    state: Pubkey,
-    params: InitializeData,
-    stake_list: Pubkey,
-    validator_list: Pubkey,
-    msol_mint: Pubkey,
-    operational_sol_account: Pubkey,
-    lp_mint: Pubkey,
-    lp_msol_leg: Pubkey,
-    treasury_msol_account: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::initialize_data"))]
+    pub params: InitializeData,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub stake_list: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub validator_list: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub msol_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub operational_sol_account: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub lp_mint: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub lp_msol_leg: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub treasury_msol_account: Pubkey,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitializeEventEvent(pub InitializeEvent);
 impl BorshSerialize for InitializeEventEvent {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
@@ -143,10 +163,13 @@ impl InitializeEventEvent {
 }
 pub const EMERGENCY_PAUSE_EVENT_EVENT_DISCM: [u8; 8] = [159, 241, 192, 232, 29, 208, 51, 21];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EmergencyPauseEvent {
-    state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub state: Pubkey,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EmergencyPauseEventEvent(pub EmergencyPauseEvent);
 impl BorshSerialize for EmergencyPauseEventEvent {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
@@ -171,10 +194,13 @@ impl EmergencyPauseEventEvent {
 }
 pub const RESUME_EVENT_EVENT_DISCM: [u8; 8] = [97, 117, 183, 115, 117, 224, 8, 229];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ResumeEvent {
-    state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub state: Pubkey,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ResumeEventEvent(pub ResumeEvent);
 impl BorshSerialize for ResumeEventEvent {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
@@ -199,12 +225,15 @@ impl ResumeEventEvent {
 }
 pub const REALLOC_VALIDATOR_LIST_EVENT_EVENT_DISCM: [u8; 8] = [70, 191, 242, 164, 56, 156, 130, 13];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReallocValidatorListEvent {
-    state: Pubkey,
-    count: u32,
-    new_capacity: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub state: Pubkey,
+    pub count: u32,
+    pub new_capacity: u32,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReallocValidatorListEventEvent(pub ReallocValidatorListEvent);
 impl BorshSerialize for ReallocValidatorListEventEvent {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
@@ -229,12 +258,15 @@ impl ReallocValidatorListEventEvent {
 }
 pub const REALLOC_STAKE_LIST_EVENT_EVENT_DISCM: [u8; 8] = [193, 129, 16, 243, 177, 131, 248, 23];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReallocStakeListEvent {
-    state: Pubkey,
-    count: u32,
-    new_capacity: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub state: Pubkey,
+    pub count: u32,
+    pub new_capacity: u32,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReallocStakeListEventEvent(pub ReallocStakeListEvent);
 impl BorshSerialize for ReallocStakeListEventEvent {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
@@ -259,24 +291,38 @@ impl ReallocStakeListEventEvent {
 }
 pub const DEACTIVATE_STAKE_EVENT_EVENT_DISCM: [u8; 8] = [2, 54, 184, 218, 78, 181, 163, 117];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeactivateStakeEvent {
-    state: Pubkey,
-    epoch: u64,
-    stake_index: u32,
-    stake_account: Pubkey,
-    last_update_stake_delegation: u64,
-    split_stake_account: Option<SplitStakeAccountInfo>,
-    validator_index: u32,
-    validator_vote: Pubkey,
-    total_stake_target: u64,
-    validator_stake_target: u64,
-    total_active_balance: u64,
-    delayed_unstake_cooling_down: u64,
-    validator_active_balance: u64,
-    total_unstake_delta: u64,
-    unstaked_amount: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub epoch: u64,
+    pub stake_index: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub stake_account: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub last_update_stake_delegation: u64,
+    pub split_stake_account: Option<SplitStakeAccountInfo>,
+    pub validator_index: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub validator_vote: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub total_stake_target: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub validator_stake_target: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub total_active_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub delayed_unstake_cooling_down: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub validator_active_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub total_unstake_delta: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub unstaked_amount: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeactivateStakeEventEvent(pub DeactivateStakeEvent);
 impl BorshSerialize for DeactivateStakeEventEvent {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
@@ -301,24 +347,38 @@ impl DeactivateStakeEventEvent {
 }
 pub const MERGE_STAKES_EVENT_EVENT_DISCM: [u8; 8] = [73, 156, 69, 233, 32, 14, 150, 65];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MergeStakesEvent {
-    state: Pubkey,
-    epoch: u64,
-    destination_stake_index: u32,
-    destination_stake_account: Pubkey,
-    last_update_destination_stake_delegation: u64,
-    source_stake_index: u32,
-    source_stake_account: Pubkey,
-    last_update_source_stake_delegation: u64,
-    validator_index: u32,
-    validator_vote: Pubkey,
-    extra_delegated: u64,
-    returned_stake_rent: u64,
-    validator_active_balance: u64,
-    total_active_balance: u64,
-    operational_sol_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub epoch: u64,
+    pub destination_stake_index: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub destination_stake_account: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub last_update_destination_stake_delegation: u64,
+    pub source_stake_index: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub source_stake_account: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub last_update_source_stake_delegation: u64,
+    pub validator_index: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub validator_vote: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub extra_delegated: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub returned_stake_rent: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub validator_active_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub total_active_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub operational_sol_balance: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MergeStakesEventEvent(pub MergeStakesEvent);
 impl BorshSerialize for MergeStakesEventEvent {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
@@ -343,28 +403,42 @@ impl MergeStakesEventEvent {
 }
 pub const REDELEGATE_EVENT_EVENT_DISCM: [u8; 8] = [241, 75, 135, 173, 204, 215, 72, 67];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RedelegateEvent {
-    state: Pubkey,
-    epoch: u64,
-    stake_index: u32,
-    stake_account: Pubkey,
-    last_update_delegation: u64,
-    source_validator_index: u32,
-    source_validator_vote: Pubkey,
-    source_validator_score: u32,
-    source_validator_balance: u64,
-    source_validator_stake_target: u64,
-    dest_validator_index: u32,
-    dest_validator_vote: Pubkey,
-    dest_validator_score: u32,
-    dest_validator_balance: u64,
-    dest_validator_stake_target: u64,
-    redelegate_amount: u64,
-    split_stake_account: Option<SplitStakeAccountInfo>,
-    redelegate_stake_index: u32,
-    redelegate_stake_account: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub epoch: u64,
+    pub stake_index: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub stake_account: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub last_update_delegation: u64,
+    pub source_validator_index: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub source_validator_vote: Pubkey,
+    pub source_validator_score: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub source_validator_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub source_validator_stake_target: u64,
+    pub dest_validator_index: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub dest_validator_vote: Pubkey,
+    pub dest_validator_score: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub dest_validator_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub dest_validator_stake_target: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub redelegate_amount: u64,
+    pub split_stake_account: Option<SplitStakeAccountInfo>,
+    pub redelegate_stake_index: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub redelegate_stake_account: Pubkey,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RedelegateEventEvent(pub RedelegateEvent);
 impl BorshSerialize for RedelegateEventEvent {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
@@ -389,22 +463,35 @@ impl RedelegateEventEvent {
 }
 pub const STAKE_RESERVE_EVENT_EVENT_DISCM: [u8; 8] = [112, 117, 149, 185, 77, 119, 190, 106];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StakeReserveEvent {
-    state: Pubkey,
-    epoch: u64,
-    stake_index: u32,
-    stake_account: Pubkey,
-    validator_index: u32,
-    validator_vote: Pubkey,
-    total_stake_target: u64,
-    validator_stake_target: u64,
-    reserve_balance: u64,
-    total_active_balance: u64,
-    validator_active_balance: u64,
-    total_stake_delta: u64,
-    amount: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub epoch: u64,
+    pub stake_index: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub stake_account: Pubkey,
+    pub validator_index: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub validator_vote: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub total_stake_target: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub validator_stake_target: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub reserve_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub total_active_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub validator_active_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub total_stake_delta: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub amount: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StakeReserveEventEvent(pub StakeReserveEvent);
 impl BorshSerialize for StakeReserveEventEvent {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
@@ -429,25 +516,38 @@ impl StakeReserveEventEvent {
 }
 pub const UPDATE_ACTIVE_EVENT_EVENT_DISCM: [u8; 8] = [251, 18, 128, 75, 208, 80, 174, 140];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateActiveEvent {
-    state: Pubkey,
-    epoch: u64,
-    stake_index: u32,
-    stake_account: Pubkey,
-    validator_index: u32,
-    validator_vote: Pubkey,
-    delegation_change: U64ValueChange,
-    delegation_growth_msol_fees: Option<u64>,
-    extra_lamports: u64,
-    extra_msol_fees: Option<u64>,
-    validator_active_balance: u64,
-    total_active_balance: u64,
-    msol_price_change: U64ValueChange,
-    reward_fee_used: Fee,
-    total_virtual_staked_lamports: u64,
-    msol_supply: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub epoch: u64,
+    pub stake_index: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub stake_account: Pubkey,
+    pub validator_index: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub validator_vote: Pubkey,
+    pub delegation_change: U64ValueChange,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::option_amount"))]
+    pub delegation_growth_msol_fees: Option<u64>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub extra_lamports: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::option_amount"))]
+    pub extra_msol_fees: Option<u64>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub validator_active_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub total_active_balance: u64,
+    pub msol_price_change: U64ValueChange,
+    pub reward_fee_used: Fee,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub total_virtual_staked_lamports: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub msol_supply: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateActiveEventEvent(pub UpdateActiveEvent);
 impl BorshSerialize for UpdateActiveEventEvent {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
@@ -472,21 +572,32 @@ impl UpdateActiveEventEvent {
 }
 pub const UPDATE_DEACTIVATED_EVENT_EVENT_DISCM: [u8; 8] = [252, 159, 177, 147, 182, 113, 186, 94];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateDeactivatedEvent {
-    state: Pubkey,
-    epoch: u64,
-    stake_index: u32,
-    stake_account: Pubkey,
-    balance_without_rent_exempt: u64,
-    last_update_delegated_lamports: u64,
-    msol_fees: Option<u64>,
-    msol_price_change: U64ValueChange,
-    reward_fee_used: Fee,
-    operational_sol_balance: u64,
-    total_virtual_staked_lamports: u64,
-    msol_supply: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub epoch: u64,
+    pub stake_index: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub stake_account: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub balance_without_rent_exempt: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub last_update_delegated_lamports: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::option_amount"))]
+    pub msol_fees: Option<u64>,
+    pub msol_price_change: U64ValueChange,
+    pub reward_fee_used: Fee,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub operational_sol_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub total_virtual_staked_lamports: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub msol_supply: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateDeactivatedEventEvent(pub UpdateDeactivatedEvent);
 impl BorshSerialize for UpdateDeactivatedEventEvent {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
@@ -511,18 +622,29 @@ impl UpdateDeactivatedEventEvent {
 }
 pub const CLAIM_EVENT_EVENT_DISCM: [u8; 8] = [93, 15, 70, 170, 48, 140, 212, 219];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClaimEvent {
-    state: Pubkey,
-    epoch: u64,
-    ticket: Pubkey,
-    beneficiary: Pubkey,
-    circulating_ticket_balance: u64,
-    circulating_ticket_count: u64,
-    reserve_balance: u64,
-    user_balance: u64,
-    amount: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub epoch: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub ticket: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub beneficiary: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub circulating_ticket_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub circulating_ticket_count: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub reserve_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub user_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub amount: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClaimEventEvent(pub ClaimEvent);
 impl BorshSerialize for ClaimEventEvent {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
@@ -547,21 +669,34 @@ impl ClaimEventEvent {
 }
 pub const ORDER_UNSTAKE_EVENT_EVENT_DISCM: [u8; 8] = [228, 63, 155, 249, 132, 160, 135, 113];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OrderUnstakeEvent {
-    state: Pubkey,
-    ticket_epoch: u64,
-    ticket: Pubkey,
-    beneficiary: Pubkey,
-    circulating_ticket_balance: u64,
-    circulating_ticket_count: u64,
-    user_msol_balance: u64,
-    burned_msol_amount: u64,
-    sol_amount: u64,
-    fee_bp_cents: u32,
-    total_virtual_staked_lamports: u64,
-    msol_supply: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub ticket_epoch: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub ticket: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub beneficiary: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub circulating_ticket_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub circulating_ticket_count: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub user_msol_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub burned_msol_amount: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub sol_amount: u64,
+    pub fee_bp_cents: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub total_virtual_staked_lamports: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub msol_supply: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OrderUnstakeEventEvent(pub OrderUnstakeEvent);
 impl BorshSerialize for OrderUnstakeEventEvent {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
@@ -586,19 +721,31 @@ impl OrderUnstakeEventEvent {
 }
 pub const ADD_LIQUIDITY_EVENT_EVENT_DISCM: [u8; 8] = [27, 178, 153, 186, 47, 196, 140, 45];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AddLiquidityEvent {
-    state: Pubkey,
-    sol_owner: Pubkey,
-    user_sol_balance: u64,
-    user_lp_balance: u64,
-    sol_leg_balance: u64,
-    lp_supply: u64,
-    sol_added_amount: u64,
-    lp_minted: u64,
-    total_virtual_staked_lamports: u64,
-    msol_supply: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub sol_owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub user_sol_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub user_lp_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub sol_leg_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub lp_supply: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub sol_added_amount: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub lp_minted: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub total_virtual_staked_lamports: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub msol_supply: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AddLiquidityEventEvent(pub AddLiquidityEvent);
 impl BorshSerialize for AddLiquidityEventEvent {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
@@ -623,24 +770,38 @@ impl AddLiquidityEventEvent {
 }
 pub const LIQUID_UNSTAKE_EVENT_EVENT_DISCM: [u8; 8] = [173, 5, 147, 15, 5, 14, 194, 116];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LiquidUnstakeEvent {
-    state: Pubkey,
-    msol_owner: Pubkey,
-    liq_pool_sol_balance: u64,
-    liq_pool_msol_balance: u64,
-    treasury_msol_balance: Option<u64>,
-    user_msol_balance: u64,
-    user_sol_balance: u64,
-    msol_amount: u64,
-    msol_fee: u64,
-    treasury_msol_cut: u64,
-    sol_amount: u64,
-    lp_liquidity_target: u64,
-    lp_max_fee: Fee,
-    lp_min_fee: Fee,
-    treasury_cut: Fee,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub msol_owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub liq_pool_sol_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub liq_pool_msol_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::option_amount"))]
+    pub treasury_msol_balance: Option<u64>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub user_msol_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub user_sol_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub msol_amount: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub msol_fee: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub treasury_msol_cut: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub sol_amount: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub lp_liquidity_target: u64,
+    pub lp_max_fee: Fee,
+    pub lp_min_fee: Fee,
+    pub treasury_cut: Fee,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LiquidUnstakeEventEvent(pub LiquidUnstakeEvent);
 impl BorshSerialize for LiquidUnstakeEventEvent {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
@@ -665,19 +826,31 @@ impl LiquidUnstakeEventEvent {
 }
 pub const REMOVE_LIQUIDITY_EVENT_EVENT_DISCM: [u8; 8] = [141, 199, 182, 123, 159, 94, 215, 102];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RemoveLiquidityEvent {
-    state: Pubkey,
-    sol_leg_balance: u64,
-    msol_leg_balance: u64,
-    user_lp_balance: u64,
-    user_sol_balance: u64,
-    user_msol_balance: u64,
-    lp_mint_supply: u64,
-    lp_burned: u64,
-    sol_out_amount: u64,
-    msol_out_amount: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub sol_leg_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub msol_leg_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub user_lp_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub user_sol_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub user_msol_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub lp_mint_supply: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub lp_burned: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub sol_out_amount: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub msol_out_amount: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RemoveLiquidityEventEvent(pub RemoveLiquidityEvent);
 impl BorshSerialize for RemoveLiquidityEventEvent {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
@@ -702,13 +875,17 @@ impl RemoveLiquidityEventEvent {
 }
 pub const ADD_VALIDATOR_EVENT_EVENT_DISCM: [u8; 8] = [190, 231, 170, 244, 14, 227, 129, 66];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AddValidatorEvent {
-    state: Pubkey,
-    validator: Pubkey,
-    index: u32,
-    score: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub validator: Pubkey,
+    pub index: u32,
+    pub score: u32,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AddValidatorEventEvent(pub AddValidatorEvent);
 impl BorshSerialize for AddValidatorEventEvent {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
@@ -733,13 +910,18 @@ impl AddValidatorEventEvent {
 }
 pub const REMOVE_VALIDATOR_EVENT_EVENT_DISCM: [u8; 8] = [67, 164, 190, 192, 156, 156, 168, 210];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RemoveValidatorEvent {
-    state: Pubkey,
-    validator: Pubkey,
-    index: u32,
-    operational_sol_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub validator: Pubkey,
+    pub index: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub operational_sol_balance: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RemoveValidatorEventEvent(pub RemoveValidatorEvent);
 impl BorshSerialize for RemoveValidatorEventEvent {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
@@ -764,13 +946,17 @@ impl RemoveValidatorEventEvent {
 }
 pub const SET_VALIDATOR_SCORE_EVENT_EVENT_DISCM: [u8; 8] = [58, 53, 237, 178, 238, 153, 85, 156];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SetValidatorScoreEvent {
-    state: Pubkey,
-    validator: Pubkey,
-    index: u32,
-    score_change: U32ValueChange,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub validator: Pubkey,
+    pub index: u32,
+    pub score_change: U32ValueChange,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SetValidatorScoreEventEvent(pub SetValidatorScoreEvent);
 impl BorshSerialize for SetValidatorScoreEventEvent {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
@@ -795,22 +981,35 @@ impl SetValidatorScoreEventEvent {
 }
 pub const DEPOSIT_STAKE_ACCOUNT_EVENT_EVENT_DISCM: [u8; 8] = [231, 203, 118, 96, 75, 116, 70, 228];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DepositStakeAccountEvent {
-    state: Pubkey,
-    stake: Pubkey,
-    delegated: u64,
-    withdrawer: Pubkey,
-    stake_index: u32,
-    validator: Pubkey,
-    validator_index: u32,
-    validator_active_balance: u64,
-    total_active_balance: u64,
-    user_msol_balance: u64,
-    msol_minted: u64,
-    total_virtual_staked_lamports: u64,
-    msol_supply: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub stake: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub delegated: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub withdrawer: Pubkey,
+    pub stake_index: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub validator: Pubkey,
+    pub validator_index: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub validator_active_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub total_active_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub user_msol_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub msol_minted: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub total_virtual_staked_lamports: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub msol_supply: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DepositStakeAccountEventEvent(pub DepositStakeAccountEvent);
 impl BorshSerialize for DepositStakeAccountEventEvent {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
@@ -835,22 +1034,37 @@ impl DepositStakeAccountEventEvent {
 }
 pub const DEPOSIT_EVENT_EVENT_DISCM: [u8; 8] = [120, 248, 61, 83, 31, 142, 107, 144];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DepositEvent {
-    state: Pubkey,
-    sol_owner: Pubkey,
-    user_sol_balance: u64,
-    user_msol_balance: u64,
-    sol_leg_balance: u64,
-    msol_leg_balance: u64,
-    reserve_balance: u64,
-    sol_swapped: u64,
-    msol_swapped: u64,
-    sol_deposited: u64,
-    msol_minted: u64,
-    total_virtual_staked_lamports: u64,
-    msol_supply: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub sol_owner: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub user_sol_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub user_msol_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub sol_leg_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub msol_leg_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub reserve_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub sol_swapped: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub msol_swapped: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub sol_deposited: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub msol_minted: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub total_virtual_staked_lamports: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub msol_supply: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DepositEventEvent(pub DepositEvent);
 impl BorshSerialize for DepositEventEvent {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
@@ -875,26 +1089,42 @@ impl DepositEventEvent {
 }
 pub const WITHDRAW_STAKE_ACCOUNT_EVENT_EVENT_DISCM: [u8; 8] = [131, 238, 39, 48, 30, 27, 165, 28];
 #[derive(Clone, Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WithdrawStakeAccountEvent {
-    state: Pubkey,
-    epoch: u64,
-    stake: Pubkey,
-    last_update_stake_delegation: u64,
-    stake_index: u32,
-    validator: Pubkey,
-    validator_index: u32,
-    user_msol_balance: u64,
-    user_msol_auth: Pubkey,
-    msol_burned: u64,
-    msol_fees: u64,
-    split_stake: Pubkey,
-    beneficiary: Pubkey,
-    split_lamports: u64,
-    fee_bp_cents: u32,
-    total_virtual_staked_lamports: u64,
-    msol_supply: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub state: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub epoch: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub stake: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub last_update_stake_delegation: u64,
+    pub stake_index: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub validator: Pubkey,
+    pub validator_index: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub user_msol_balance: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub user_msol_auth: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub msol_burned: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub msol_fees: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub split_stake: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
+    pub beneficiary: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub split_lamports: u64,
+    pub fee_bp_cents: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub total_virtual_staked_lamports: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
+    pub msol_supply: u64,
 }
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WithdrawStakeAccountEventEvent(pub WithdrawStakeAccountEvent);
 impl BorshSerialize for WithdrawStakeAccountEventEvent {
     fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
@@ -917,3 +1147,391 @@ impl WithdrawStakeAccountEventEvent {
         Ok(Self(WithdrawStakeAccountEvent::deserialize(buf)?))
     }
 }
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MarinadeFinanceEvent {
+    ChangeAuthority(ChangeAuthorityEvent),
+    ConfigLp(ConfigLpEvent),
+    ConfigMarinade(ConfigMarinadeEvent),
+    Initialize(InitializeEvent),
+    EmergencyPause(EmergencyPauseEvent),
+    Resume(ResumeEvent),
+    ReallocValidatorList(ReallocValidatorListEvent),
+    ReallocStakeList(ReallocStakeListEvent),
+    DeactivateStake(DeactivateStakeEvent),
+    MergeStakes(MergeStakesEvent),
+    Redelegate(RedelegateEvent),
+    StakeReserve(StakeReserveEvent),
+    UpdateActive(UpdateActiveEvent),
+    UpdateDeactivated(UpdateDeactivatedEvent),
+    Claim(ClaimEvent),
+    OrderUnstake(OrderUnstakeEvent),
+    AddLiquidity(AddLiquidityEvent),
+    LiquidUnstake(LiquidUnstakeEvent),
+    RemoveLiquidity(RemoveLiquidityEvent),
+    AddValidator(AddValidatorEvent),
+    RemoveValidator(RemoveValidatorEvent),
+    SetValidatorScore(SetValidatorScoreEvent),
+    DepositStakeAccount(DepositStakeAccountEvent),
+    Deposit(DepositEvent),
+    WithdrawStakeAccount(WithdrawStakeAccountEvent),
+}
+impl BorshSerialize for MarinadeFinanceEvent {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        match self {
+            Self::ChangeAuthority(event) => {
+                CHANGE_AUTHORITY_EVENT_EVENT_DISCM.serialize(writer)?;
+                event.serialize(writer)
+            }
+            Self::ConfigLp(event) => {
+                CONFIG_LP_EVENT_EVENT_DISCM.serialize(writer)?;
+                event.serialize(writer)
+            }
+            Self::ConfigMarinade(event) => {
+                CONFIG_MARINADE_EVENT_EVENT_DISCM.serialize(writer)?;
+                event.serialize(writer)
+            }
+            Self::Initialize(event) => {
+                INITIALIZE_EVENT_EVENT_DISCM.serialize(writer)?;
+                event.serialize(writer)
+            }
+            Self::EmergencyPause(event) => {
+                EMERGENCY_PAUSE_EVENT_EVENT_DISCM.serialize(writer)?;
+                event.serialize(writer)
+            }
+            Self::Resume(event) => {
+                RESUME_EVENT_EVENT_DISCM.serialize(writer)?;
+                event.serialize(writer)
+            }
+            Self::ReallocValidatorList(event) => {
+                REALLOC_VALIDATOR_LIST_EVENT_EVENT_DISCM.serialize(writer)?;
+                event.serialize(writer)
+            }
+            Self::ReallocStakeList(event) => {
+                REALLOC_STAKE_LIST_EVENT_EVENT_DISCM.serialize(writer)?;
+                event.serialize(writer)
+            }
+            Self::DeactivateStake(event) => {
+                DEACTIVATE_STAKE_EVENT_EVENT_DISCM.serialize(writer)?;
+                event.serialize(writer)
+            }
+            Self::MergeStakes(event) => {
+                MERGE_STAKES_EVENT_EVENT_DISCM.serialize(writer)?;
+                event.serialize(writer)
+            }
+            Self::Redelegate(event) => {
+                REDELEGATE_EVENT_EVENT_DISCM.serialize(writer)?;
+                event.serialize(writer)
+            }
+            Self::StakeReserve(event) => {
+                STAKE_RESERVE_EVENT_EVENT_DISCM.serialize(writer)?;
+                event.serialize(writer)
+            }
+            Self::UpdateActive(event) => {
+                UPDATE_ACTIVE_EVENT_EVENT_DISCM.serialize(writer)?;
+                event.serialize(writer)
+            }
+            Self::UpdateDeactivated(event) => {
+                UPDATE_DEACTIVATED_EVENT_EVENT_DISCM.serialize(writer)?;
+                event.serialize(writer)
+            }
+            Self::Claim(event) => {
+                CLAIM_EVENT_EVENT_DISCM.serialize(writer)?;
+                event.serialize(writer)
+            }
+            Self::OrderUnstake(event) => {
+                ORDER_UNSTAKE_EVENT_EVENT_DISCM.serialize(writer)?;
+                event.serialize(writer)
+            }
+            Self::AddLiquidity(event) => {
+                ADD_LIQUIDITY_EVENT_EVENT_DISCM.serialize(writer)?;
+                event.serialize(writer)
+            }
+            Self::LiquidUnstake(event) => {
+                LIQUID_UNSTAKE_EVENT_EVENT_DISCM.serialize(writer)?;
+                event.serialize(writer)
+            }
+            Self::RemoveLiquidity(event) => {
+                REMOVE_LIQUIDITY_EVENT_EVENT_DISCM.serialize(writer)?;
+                event.serialize(writer)
+            }
+            Self::AddValidator(event) => {
+                ADD_VALIDATOR_EVENT_EVENT_DISCM.serialize(writer)?;
+                event.serialize(writer)
+            }
+            Self::RemoveValidator(event) => {
+                REMOVE_VALIDATOR_EVENT_EVENT_DISCM.serialize(writer)?;
+                event.serialize(writer)
+            }
+            Self::SetValidatorScore(event) => {
+                SET_VALIDATOR_SCORE_EVENT_EVENT_DISCM.serialize(writer)?;
+                event.serialize(writer)
+            }
+            Self::DepositStakeAccount(event) => {
+                DEPOSIT_STAKE_ACCOUNT_EVENT_EVENT_DISCM.serialize(writer)?;
+                event.serialize(writer)
+            }
+            Self::Deposit(event) => {
+                DEPOSIT_EVENT_EVENT_DISCM.serialize(writer)?;
+                event.serialize(writer)
+            }
+            Self::WithdrawStakeAccount(event) => {
+                WITHDRAW_STAKE_ACCOUNT_EVENT_EVENT_DISCM.serialize(writer)?;
+                event.serialize(writer)
+            }
+        }
+    }
+}
+impl MarinadeFinanceEvent {
+    pub fn deserialize(buf: &mut &[u8]) -> std::io::Result<Self> {
+        let maybe_discm = <[u8; 8]>::deserialize(buf)?;
+        match maybe_discm {
+            CHANGE_AUTHORITY_EVENT_EVENT_DISCM => Ok(Self::ChangeAuthority(
+                ChangeAuthorityEvent::deserialize(buf)?,
+            )),
+            CONFIG_LP_EVENT_EVENT_DISCM => Ok(Self::ConfigLp(ConfigLpEvent::deserialize(buf)?)),
+            CONFIG_MARINADE_EVENT_EVENT_DISCM => {
+                Ok(Self::ConfigMarinade(ConfigMarinadeEvent::deserialize(buf)?))
+            }
+            INITIALIZE_EVENT_EVENT_DISCM => {
+                Ok(Self::Initialize(InitializeEvent::deserialize(buf)?))
+            }
+            EMERGENCY_PAUSE_EVENT_EVENT_DISCM => {
+                Ok(Self::EmergencyPause(EmergencyPauseEvent::deserialize(buf)?))
+            }
+            RESUME_EVENT_EVENT_DISCM => Ok(Self::Resume(ResumeEvent::deserialize(buf)?)),
+            REALLOC_VALIDATOR_LIST_EVENT_EVENT_DISCM => Ok(Self::ReallocValidatorList(
+                ReallocValidatorListEvent::deserialize(buf)?,
+            )),
+            REALLOC_STAKE_LIST_EVENT_EVENT_DISCM => Ok(Self::ReallocStakeList(
+                ReallocStakeListEvent::deserialize(buf)?,
+            )),
+            DEACTIVATE_STAKE_EVENT_EVENT_DISCM => Ok(Self::DeactivateStake(
+                DeactivateStakeEvent::deserialize(buf)?,
+            )),
+            MERGE_STAKES_EVENT_EVENT_DISCM => {
+                Ok(Self::MergeStakes(MergeStakesEvent::deserialize(buf)?))
+            }
+            REDELEGATE_EVENT_EVENT_DISCM => {
+                Ok(Self::Redelegate(RedelegateEvent::deserialize(buf)?))
+            }
+            STAKE_RESERVE_EVENT_EVENT_DISCM => {
+                Ok(Self::StakeReserve(StakeReserveEvent::deserialize(buf)?))
+            }
+            UPDATE_ACTIVE_EVENT_EVENT_DISCM => {
+                Ok(Self::UpdateActive(UpdateActiveEvent::deserialize(buf)?))
+            }
+            UPDATE_DEACTIVATED_EVENT_EVENT_DISCM => Ok(Self::UpdateDeactivated(
+                UpdateDeactivatedEvent::deserialize(buf)?,
+            )),
+            CLAIM_EVENT_EVENT_DISCM => Ok(Self::Claim(ClaimEvent::deserialize(buf)?)),
+            ORDER_UNSTAKE_EVENT_EVENT_DISCM => {
+                Ok(Self::OrderUnstake(OrderUnstakeEvent::deserialize(buf)?))
+            }
+            ADD_LIQUIDITY_EVENT_EVENT_DISCM => {
+                Ok(Self::AddLiquidity(AddLiquidityEvent::deserialize(buf)?))
+            }
+            LIQUID_UNSTAKE_EVENT_EVENT_DISCM => {
+                Ok(Self::LiquidUnstake(LiquidUnstakeEvent::deserialize(buf)?))
+            }
+            REMOVE_LIQUIDITY_EVENT_EVENT_DISCM => Ok(Self::RemoveLiquidity(
+                RemoveLiquidityEvent::deserialize(buf)?,
+            )),
+            ADD_VALIDATOR_EVENT_EVENT_DISCM => {
+                Ok(Self::AddValidator(AddValidatorEvent::deserialize(buf)?))
+            }
+            REMOVE_VALIDATOR_EVENT_EVENT_DISCM => Ok(Self::RemoveValidator(
+                RemoveValidatorEvent::deserialize(buf)?,
+            )),
+            SET_VALIDATOR_SCORE_EVENT_EVENT_DISCM => Ok(Self::SetValidatorScore(
+                SetValidatorScoreEvent::deserialize(buf)?,
+            )),
+            DEPOSIT_STAKE_ACCOUNT_EVENT_EVENT_DISCM => Ok(Self::DepositStakeAccount(
+                DepositStakeAccountEvent::deserialize(buf)?,
+            )),
+            DEPOSIT_EVENT_EVENT_DISCM => Ok(Self::Deposit(DepositEvent::deserialize(buf)?)),
+            WITHDRAW_STAKE_ACCOUNT_EVENT_EVENT_DISCM => Ok(Self::WithdrawStakeAccount(
+                WithdrawStakeAccountEvent::deserialize(buf)?,
+            )),
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!("discm {:?} not found", maybe_discm),
+            )),
+        }
+    }
+}
+impl From<ChangeAuthorityEventEvent> for MarinadeFinanceEvent {
+    fn from(event: ChangeAuthorityEventEvent) -> Self {
+        Self::ChangeAuthority(event.0)
+    }
+}
+impl From<ConfigLpEventEvent> for MarinadeFinanceEvent {
+    fn from(event: ConfigLpEventEvent) -> Self {
+        Self::ConfigLp(event.0)
+    }
+}
+impl From<ConfigMarinadeEventEvent> for MarinadeFinanceEvent {
+    fn from(event: ConfigMarinadeEventEvent) -> Self {
+        Self::ConfigMarinade(event.0)
+    }
+}
+impl From<InitializeEventEvent> for MarinadeFinanceEvent {
+    fn from(event: InitializeEventEvent) -> Self {
+        Self::Initialize(event.0)
+    }
+}
+impl From<EmergencyPauseEventEvent> for MarinadeFinanceEvent {
+    fn from(event: EmergencyPauseEventEvent) -> Self {
+        Self::EmergencyPause(event.0)
+    }
+}
+impl From<ResumeEventEvent> for MarinadeFinanceEvent {
+    fn from(event: ResumeEventEvent) -> Self {
+        Self::Resume(event.0)
+    }
+}
+impl From<ReallocValidatorListEventEvent> for MarinadeFinanceEvent {
+    fn from(event: ReallocValidatorListEventEvent) -> Self {
+        Self::ReallocValidatorList(event.0)
+    }
+}
+impl From<ReallocStakeListEventEvent> for MarinadeFinanceEvent {
+    fn from(event: ReallocStakeListEventEvent) -> Self {
+        Self::ReallocStakeList(event.0)
+    }
+}
+impl From<DeactivateStakeEventEvent> for MarinadeFinanceEvent {
+    fn from(event: DeactivateStakeEventEvent) -> Self {
+        Self::DeactivateStake(event.0)
+    }
+}
+impl From<MergeStakesEventEvent> for MarinadeFinanceEvent {
+    fn from(event: MergeStakesEventEvent) -> Self {
+        Self::MergeStakes(event.0)
+    }
+}
+impl From<RedelegateEventEvent> for MarinadeFinanceEvent {
+    fn from(event: RedelegateEventEvent) -> Self {
+        Self::Redelegate(event.0)
+    }
+}
+impl From<StakeReserveEventEvent> for MarinadeFinanceEvent {
+    fn from(event: StakeReserveEventEvent) -> Self {
+        Self::StakeReserve(event.0)
+    }
+}
+impl From<UpdateActiveEventEvent> for MarinadeFinanceEvent {
+    fn from(event: UpdateActiveEventEvent) -> Self {
+        Self::UpdateActive(event.0)
+    }
+}
+impl From<UpdateDeactivatedEventEvent> for MarinadeFinanceEvent {
+    fn from(event: UpdateDeactivatedEventEvent) -> Self {
+        Self::UpdateDeactivated(event.0)
+    }
+}
+impl From<ClaimEventEvent> for MarinadeFinanceEvent {
+    fn from(event: ClaimEventEvent) -> Self {
+        Self::Claim(event.0)
+    }
+}
+impl From<OrderUnstakeEventEvent> for MarinadeFinanceEvent {
+    fn from(event: OrderUnstakeEventEvent) -> Self {
+        Self::OrderUnstake(event.0)
+    }
+}
+impl From<AddLiquidityEventEvent> for MarinadeFinanceEvent {
+    fn from(event: AddLiquidityEventEvent) -> Self {
+        Self::AddLiquidity(event.0)
+    }
+}
+impl From<LiquidUnstakeEventEvent> for MarinadeFinanceEvent {
+    fn from(event: LiquidUnstakeEventEvent) -> Self {
+        Self::LiquidUnstake(event.0)
+    }
+}
+impl From<RemoveLiquidityEventEvent> for MarinadeFinanceEvent {
+    fn from(event: RemoveLiquidityEventEvent) -> Self {
+        Self::RemoveLiquidity(event.0)
+    }
+}
+impl From<AddValidatorEventEvent> for MarinadeFinanceEvent {
+    fn from(event: AddValidatorEventEvent) -> Self {
+        Self::AddValidator(event.0)
+    }
+}
+impl From<RemoveValidatorEventEvent> for MarinadeFinanceEvent {
+    fn from(event: RemoveValidatorEventEvent) -> Self {
+        Self::RemoveValidator(event.0)
+    }
+}
+impl From<SetValidatorScoreEventEvent> for MarinadeFinanceEvent {
+    fn from(event: SetValidatorScoreEventEvent) -> Self {
+        Self::SetValidatorScore(event.0)
+    }
+}
+impl From<DepositStakeAccountEventEvent> for MarinadeFinanceEvent {
+    fn from(event: DepositStakeAccountEventEvent) -> Self {
+        Self::DepositStakeAccount(event.0)
+    }
+}
+impl From<DepositEventEvent> for MarinadeFinanceEvent {
+    fn from(event: DepositEventEvent) -> Self {
+        Self::Deposit(event.0)
+    }
+}
+impl From<WithdrawStakeAccountEventEvent> for MarinadeFinanceEvent {
+    fn from(event: WithdrawStakeAccountEventEvent) -> Self {
+        Self::WithdrawStakeAccount(event.0)
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::patterned;
+    #[test]
+    fn event_round_trip() {
+        let events = [
+            MarinadeFinanceEvent::Deposit(patterned()),
+            MarinadeFinanceEvent::Claim(patterned()),
+            MarinadeFinanceEvent::ConfigLp(ConfigLpEvent {
+                state: Pubkey::new_unique(),
+                min_fee_change: None,
+                max_fee_change: Some(FeeValueChange {
+                    old: Fee { basis_points: 300 },
+                    new: Fee { basis_points: 250 },
+                }),
+                liquidity_target_change: Some(U64ValueChange {
+                    old: 1_000,
+                    new: 2_000,
+                }),
+                treasury_cut_change: None,
+            }),
+            MarinadeFinanceEvent::Resume(patterned()),
+        ];
+        for event in events {
+            let bytes = event.try_to_vec().unwrap();
+            assert_eq!(
+                MarinadeFinanceEvent::deserialize(&mut bytes.as_slice()).unwrap(),
+                event
+            );
+        }
+        let event = DepositEventEvent(patterned());
+        let bytes = event.try_to_vec().unwrap();
+        assert_eq!(&bytes[..8], &DEPOSIT_EVENT_EVENT_DISCM);
+        assert_eq!(
+            MarinadeFinanceEvent::deserialize(&mut bytes.as_slice()).unwrap(),
+            MarinadeFinanceEvent::from(event)
+        );
+    }
+    #[test]
+    fn unknown_discm() {
+        let err = MarinadeFinanceEvent::deserialize(&mut [0u8; 64].as_slice()).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::Other);
+        assert_eq!(err.to_string(), format!("discm {:?} not found", [0u8; 8]));
+        let bytes = MarinadeFinanceEvent::Claim(patterned())
+            .try_to_vec()
+            .unwrap();
+        assert!(DepositEventEvent::deserialize(&mut bytes.as_slice()).is_err());
+    }
+}
@@ -0,0 +1,218 @@
+use crate::*;
+use std::{fmt, str::FromStr};
+use thiserror::Error;
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+pub enum FeeError {
+    #[error("Invalid fee {0:?}")]
+    Invalid(String),
+    #[error("Fee has more decimal places than its type can represent")]
+    TooPrecise,
+    #[error(transparent)]
+    Overflow(#[from] MarinadeFinanceError),
+}
+impl Fee {
+    pub const MAX_BASIS_POINTS: u32 = 10_000;
+    pub const fn from_basis_points(basis_points: u32) -> Self {
+        Self { basis_points }
+    }
+    pub const fn from_percent(percent: u32) -> Self {
+        Self::from_basis_points(percent.saturating_mul(100))
+    }
+    pub fn check(&self) -> Result<(), MarinadeFinanceError> {
+        if self.basis_points > Self::MAX_BASIS_POINTS {
+            return Err(MarinadeFinanceError::BasisPointsOverflow);
+        }
+        Ok(())
+    }
+    pub fn apply(&self, amount: u64) -> Result<u64, MarinadeFinanceError> {
+        self.check()?;
+        u64::try_from(amount as u128 * self.basis_points as u128 / Self::MAX_BASIS_POINTS as u128)
+            .map_err(|_| MarinadeFinanceError::CalculationFailure)
+    }
+}
+impl fmt::Display for Fee {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}.{:0>2}%",
+            self.basis_points / 100,
+            self.basis_points % 100
+        )
+    }
+}
+impl FromStr for Fee {
+    type Err = FeeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fee = Self::from_basis_points(parse_percent(s, 2)?);
+        fee.check()?;
+        Ok(fee)
+    }
+}
+impl TryFrom<FeeCents> for Fee {
+    type Error = FeeError;
+    fn try_from(fee: FeeCents) -> Result<Self, Self::Error> {
+        if fee.bp_cents % 100 != 0 {
+            return Err(FeeError::TooPrecise);
+        }
+        Ok(Self::from_basis_points(fee.bp_cents / 100))
+    }
+}
+impl FeeCents {
+    pub const MAX_BP_CENTS: u32 = 1_000_000;
+    pub const fn from_bp_cents(bp_cents: u32) -> Self {
+        Self { bp_cents }
+    }
+    pub const fn from_basis_points(basis_points: u32) -> Self {
+        Self::from_bp_cents(basis_points.saturating_mul(100))
+    }
+    pub const fn from_percent(percent: u32) -> Self {
+        Self::from_bp_cents(percent.saturating_mul(10_000))
+    }
+    pub fn check(&self) -> Result<(), MarinadeFinanceError> {
+        if self.bp_cents > Self::MAX_BP_CENTS {
+            return Err(MarinadeFinanceError::BasisPointCentsOverflow);
+        }
+        Ok(())
+    }
+    pub fn apply(&self, amount: u64) -> Result<u64, MarinadeFinanceError> {
+        self.check()?;
+        u64::try_from(amount as u128 * self.bp_cents as u128 / Self::MAX_BP_CENTS as u128)
+            .map_err(|_| MarinadeFinanceError::CalculationFailure)
+    }
+}
+impl fmt::Display for FeeCents {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}.{:0>4}%",
+            self.bp_cents / 10_000,
+            self.bp_cents % 10_000
+        )
+    }
+}
+impl FromStr for FeeCents {
+    type Err = FeeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fee = Self::from_bp_cents(parse_percent(s, 4)?);
+        fee.check()?;
+        Ok(fee)
+    }
+}
+impl From<Fee> for FeeCents {
+    fn from(fee: Fee) -> Self {
+        Self::from_basis_points(fee.basis_points)
+    }
+}
+fn parse_percent(s: &str, decimals: usize) -> Result<u32, FeeError> {
+    let invalid = || FeeError::Invalid(s.to_owned());
+    let trimmed = s.trim();
+    let number = trimmed.strip_suffix('%').unwrap_or(trimmed).trim_end();
+    let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+    if whole.is_empty()
+        || !whole
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.len() > decimals {
+        return Err(FeeError::TooPrecise);
+    }
+    let scale = 10u64.pow(decimals as u32);
+    let whole: u64 = whole.parse().unwrap_or(u64::MAX);
+    let fraction: u64 = if fraction.is_empty() {
+        0
+    } else {
+        fraction.parse::<u64>().map_err(|_| invalid())?
+            * 10u64.pow((decimals - fraction.len()) as u32)
+    };
+    let units = whole
+        .checked_mul(scale)
+        .and_then(|units| units.checked_add(fraction))
+        .unwrap_or(u64::MAX);
+    Ok(u32::try_from(units).unwrap_or(u32::MAX))
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn display_from_str_round_trip() {
+        for (s, basis_points) in [("0.25%", 25), ("1.00%", 100), ("100.00%", 10_000)] {
+            let fee: Fee = s.parse().unwrap();
+            assert_eq!(fee, Fee::from_basis_points(basis_points));
+            assert_eq!(fee.to_string(), s);
+        }
+        assert_eq!("1%".parse::<Fee>().unwrap(), Fee::from_percent(1));
+        assert_eq!(
+            " 0.5 % ".parse::<Fee>().unwrap(),
+            Fee::from_basis_points(50)
+        );
+        for (s, bp_cents) in [("0.0001%", 1), ("0.2500%", 2_500), ("1.0000%", 10_000)] {
+            let fee: FeeCents = s.parse().unwrap();
+            assert_eq!(fee, FeeCents::from_bp_cents(bp_cents));
+            assert_eq!(fee.to_string(), s);
+        }
+        assert_eq!("1%".parse::<FeeCents>().unwrap(), FeeCents::from_percent(1));
+    }
+    #[test]
+    fn too_precise() {
+        assert_eq!("0.001%".parse::<Fee>(), Err(FeeError::TooPrecise));
+        assert_eq!("0.00001%".parse::<FeeCents>(), Err(FeeError::TooPrecise));
+        assert_eq!("0.0100%".parse::<Fee>().unwrap(), Fee::from_basis_points(1));
+        assert_eq!(
+            Fee::try_from(FeeCents::from_bp_cents(2_550)),
+            Err(FeeError::TooPrecise)
+        );
+        assert_eq!(
+            Fee::try_from(FeeCents::from_bp_cents(2_500)).unwrap(),
+            Fee::from_basis_points(25)
+        );
+        assert_eq!(
+            FeeCents::from(Fee::from_basis_points(25)),
+            FeeCents::from_bp_cents(2_500)
+        );
+    }
+    #[test]
+    fn overflow() {
+        let overflow = Err(FeeError::Overflow(
+            MarinadeFinanceError::BasisPointsOverflow,
+        ));
+        assert_eq!("100.01%".parse::<Fee>(), overflow);
+        assert_eq!("42949673%".parse::<Fee>(), overflow);
+        assert_eq!("99999999999999999999999%".parse::<Fee>(), overflow);
+        assert_eq!(
+            "100.0001%".parse::<FeeCents>(),
+            Err(FeeError::Overflow(
+                MarinadeFinanceError::BasisPointCentsOverflow
+            ))
+        );
+    }
+    #[test]
+    fn invalid() {
+        for s in ["", "%", "abc", "1.2.3%", "-1%", ".5%", "1e2%"] {
+            assert_eq!(
+                s.parse::<Fee>(),
+                Err(FeeError::Invalid(s.to_owned())),
+                "{:?}",
+                s
+            );
+        }
+    }
+    #[test]
+    fn apply_rounds_down() {
+        assert_eq!(Fee::from_basis_points(30).apply(999_999), Ok(2_999));
+        assert_eq!(Fee::from_basis_points(10_000).apply(u64::MAX), Ok(u64::MAX));
+        assert_eq!(FeeCents::from_bp_cents(1).apply(999_999), Ok(0));
+        assert_eq!(FeeCents::from_bp_cents(1).apply(1_000_000), Ok(1));
+        assert_eq!(
+            Fee::from_basis_points(10_001).apply(1),
+            Err(MarinadeFinanceError::BasisPointsOverflow)
+        );
+        assert_eq!(
+            FeeCents::from_bp_cents(1_000_001).apply(1),
+            Err(MarinadeFinanceError::BasisPointCentsOverflow)
+        );
+    }
+}
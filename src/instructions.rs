@@ -3,7 +3,7 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
     account_info::AccountInfo,
     entrypoint::ProgramResult,
-    instruction::{AccountMeta, Instruction},
+    instruction::{AccountMeta, CompiledInstruction, Instruction},
     program::{invoke, invoke_signed},
     program_error::ProgramError,
     pubkey::Pubkey,
@@ -413,6 +413,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN]>
     }
 }
 pub const INITIALIZE_IX_DISCM: [u8; 8] = [175, 175, 109, 31, 13, 152, 155, 237];
+pub const INITIALIZE_IX_DATA_LEN: usize = 152;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InitializeIxArgs {
@@ -450,26 +451,74 @@ impl InitializeIxData {
         self.serialize(&mut data)?;
         Ok(data)
     }
+    pub fn to_bytes(&self) -> [u8; INITIALIZE_IX_DATA_LEN] {
+        let mut data = [0u8; INITIALIZE_IX_DATA_LEN];
+        data[..8].copy_from_slice(&INITIALIZE_IX_DISCM);
+        data[8..40].copy_from_slice(self.0.data.admin_authority.as_ref());
+        data[40..72].copy_from_slice(self.0.data.validator_manager_authority.as_ref());
+        data[72..80].copy_from_slice(&self.0.data.min_stake.to_le_bytes());
+        data[80..84].copy_from_slice(&self.0.data.rewards_fee.basis_points.to_le_bytes());
+        data[84..92].copy_from_slice(&self.0.data.liq_pool.lp_liquidity_target.to_le_bytes());
+        data[92..96].copy_from_slice(&self.0.data.liq_pool.lp_max_fee.basis_points.to_le_bytes());
+        data[96..100].copy_from_slice(&self.0.data.liq_pool.lp_min_fee.basis_points.to_le_bytes());
+        data[100..104].copy_from_slice(
+            &self
+                .0
+                .data
+                .liq_pool
+                .lp_treasury_cut
+                .basis_points
+                .to_le_bytes(),
+        );
+        data[104..108].copy_from_slice(&self.0.data.additional_stake_record_space.to_le_bytes());
+        data[108..112]
+            .copy_from_slice(&self.0.data.additional_validator_record_space.to_le_bytes());
+        data[112..120].copy_from_slice(&self.0.data.slots_for_stake_delta.to_le_bytes());
+        data[120..152].copy_from_slice(self.0.data.pause_authority.as_ref());
+        data
+    }
+}
+pub fn initialize_ix_with_program_id<K: Into<InitializeKeys>, A: Into<InitializeIxArgs>>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    Ok(initialize_ix_fixed_with_program_id(
+        program_id, accounts, args,
+    ))
 }
 pub fn initialize_ix<K: Into<InitializeKeys>, A: Into<InitializeIxArgs>>(
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
+    initialize_ix_with_program_id(crate::ID, accounts, args)
+}
+pub fn initialize_ix_fixed_with_program_id<K: Into<InitializeKeys>, A: Into<InitializeIxArgs>>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> Instruction {
     let keys: InitializeKeys = accounts.into();
     let metas: [AccountMeta; INITIALIZE_IX_ACCOUNTS_LEN] = keys.into();
     let args_full: InitializeIxArgs = args.into();
     let data: InitializeIxData = args_full.into();
-    Ok(Instruction {
-        program_id: crate::ID,
+    Instruction {
+        program_id,
         accounts: Vec::from(metas),
-        data: data.try_to_vec()?,
-    })
+        data: Vec::from(data.to_bytes()),
+    }
+}
+pub fn initialize_ix_fixed<K: Into<InitializeKeys>, A: Into<InitializeIxArgs>>(
+    accounts: K,
+    args: A,
+) -> Instruction {
+    initialize_ix_fixed_with_program_id(crate::ID, accounts, args)
 }
 pub fn initialize_invoke<'info, A: Into<InitializeIxArgs>>(
     accounts: InitializeAccounts<'_, 'info>,
     args: A,
 ) -> ProgramResult {
-    let ix = initialize_ix(accounts, args)?;
+    let ix = initialize_ix_fixed(accounts, args);
     let account_info: [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke(&ix, &account_info)
 }
@@ -478,10 +527,58 @@ pub fn initialize_invoke_signed<'info, A: Into<InitializeIxArgs>>(
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = initialize_ix(accounts, args)?;
+    let ix = initialize_ix_fixed(accounts, args);
+    let account_info: [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn initialize_invoke_with_program_id<'info, A: Into<InitializeIxArgs>>(
+    program_id: Pubkey,
+    accounts: InitializeAccounts<'_, 'info>,
+    args: A,
+) -> ProgramResult {
+    let ix = initialize_ix_fixed_with_program_id(program_id, accounts, args);
+    let account_info: [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn initialize_invoke_signed_with_program_id<'info, A: Into<InitializeIxArgs>>(
+    program_id: Pubkey,
+    accounts: InitializeAccounts<'_, 'info>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = initialize_ix_fixed_with_program_id(program_id, accounts, args);
     let account_info: [AccountInfo<'info>; INITIALIZE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
+pub fn initialize_invoke_with_slice<A: Into<InitializeIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+) -> ProgramResult {
+    let args_full: InitializeIxArgs = args.into();
+    let data: InitializeIxData = args_full.into();
+    let ix = ix_from_slice::<INITIALIZE_IX_ACCOUNTS_LEN, InitializeKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke(&ix, accounts)
+}
+pub fn initialize_invoke_signed_with_slice<A: Into<InitializeIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let args_full: InitializeIxArgs = args.into();
+    let data: InitializeIxData = args_full.into();
+    let ix = ix_from_slice::<INITIALIZE_IX_ACCOUNTS_LEN, InitializeKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke_signed(&ix, accounts, seeds)
+}
 pub fn initialize_verify_account_keys(
     accounts: InitializeAccounts<'_, '_>,
     keys: InitializeKeys,
@@ -624,7 +721,11 @@ impl ChangeAuthorityIxData {
         Ok(data)
     }
 }
-pub fn change_authority_ix<K: Into<ChangeAuthorityKeys>, A: Into<ChangeAuthorityIxArgs>>(
+pub fn change_authority_ix_with_program_id<
+    K: Into<ChangeAuthorityKeys>,
+    A: Into<ChangeAuthorityIxArgs>,
+>(
+    program_id: Pubkey,
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
@@ -633,11 +734,17 @@ pub fn change_authority_ix<K: Into<ChangeAuthorityKeys>, A: Into<ChangeAuthority
     let args_full: ChangeAuthorityIxArgs = args.into();
     let data: ChangeAuthorityIxData = args_full.into();
     Ok(Instruction {
-        program_id: crate::ID,
+        program_id,
         accounts: Vec::from(metas),
         data: data.try_to_vec()?,
     })
 }
+pub fn change_authority_ix<K: Into<ChangeAuthorityKeys>, A: Into<ChangeAuthorityIxArgs>>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    change_authority_ix_with_program_id(crate::ID, accounts, args)
+}
 pub fn change_authority_invoke<'info, A: Into<ChangeAuthorityIxArgs>>(
     accounts: ChangeAuthorityAccounts<'_, 'info>,
     args: A,
@@ -655,6 +762,54 @@ pub fn change_authority_invoke_signed<'info, A: Into<ChangeAuthorityIxArgs>>(
     let account_info: [AccountInfo<'info>; CHANGE_AUTHORITY_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
+pub fn change_authority_invoke_with_program_id<'info, A: Into<ChangeAuthorityIxArgs>>(
+    program_id: Pubkey,
+    accounts: ChangeAuthorityAccounts<'_, 'info>,
+    args: A,
+) -> ProgramResult {
+    let ix = change_authority_ix_with_program_id(program_id, accounts, args)?;
+    let account_info: [AccountInfo<'info>; CHANGE_AUTHORITY_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn change_authority_invoke_signed_with_program_id<'info, A: Into<ChangeAuthorityIxArgs>>(
+    program_id: Pubkey,
+    accounts: ChangeAuthorityAccounts<'_, 'info>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = change_authority_ix_with_program_id(program_id, accounts, args)?;
+    let account_info: [AccountInfo<'info>; CHANGE_AUTHORITY_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn change_authority_invoke_with_slice<A: Into<ChangeAuthorityIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+) -> ProgramResult {
+    let args_full: ChangeAuthorityIxArgs = args.into();
+    let data: ChangeAuthorityIxData = args_full.into();
+    let ix = ix_from_slice::<CHANGE_AUTHORITY_IX_ACCOUNTS_LEN, ChangeAuthorityKeys>(
+        program_id,
+        accounts,
+        data.try_to_vec()?,
+    )?;
+    invoke(&ix, accounts)
+}
+pub fn change_authority_invoke_signed_with_slice<A: Into<ChangeAuthorityIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let args_full: ChangeAuthorityIxArgs = args.into();
+    let data: ChangeAuthorityIxData = args_full.into();
+    let ix = ix_from_slice::<CHANGE_AUTHORITY_IX_ACCOUNTS_LEN, ChangeAuthorityKeys>(
+        program_id,
+        accounts,
+        data.try_to_vec()?,
+    )?;
+    invoke_signed(&ix, accounts, seeds)
+}
 pub fn change_authority_verify_account_keys(
     accounts: ChangeAuthorityAccounts<'_, '_>,
     keys: ChangeAuthorityKeys,
@@ -825,6 +980,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_VALIDATOR_IX_ACCOUNTS_LEN]>
     }
 }
 pub const ADD_VALIDATOR_IX_DISCM: [u8; 8] = [250, 113, 53, 54, 141, 117, 215, 185];
+pub const ADD_VALIDATOR_IX_DATA_LEN: usize = 12;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AddValidatorIxArgs {
@@ -862,26 +1018,57 @@ impl AddValidatorIxData {
         self.serialize(&mut data)?;
         Ok(data)
     }
+    pub fn to_bytes(&self) -> [u8; ADD_VALIDATOR_IX_DATA_LEN] {
+        let mut data = [0u8; ADD_VALIDATOR_IX_DATA_LEN];
+        data[..8].copy_from_slice(&ADD_VALIDATOR_IX_DISCM);
+        data[8..12].copy_from_slice(&self.0.score.to_le_bytes());
+        data
+    }
+}
+pub fn add_validator_ix_with_program_id<K: Into<AddValidatorKeys>, A: Into<AddValidatorIxArgs>>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    Ok(add_validator_ix_fixed_with_program_id(
+        program_id, accounts, args,
+    ))
 }
 pub fn add_validator_ix<K: Into<AddValidatorKeys>, A: Into<AddValidatorIxArgs>>(
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
+    add_validator_ix_with_program_id(crate::ID, accounts, args)
+}
+pub fn add_validator_ix_fixed_with_program_id<
+    K: Into<AddValidatorKeys>,
+    A: Into<AddValidatorIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> Instruction {
     let keys: AddValidatorKeys = accounts.into();
     let metas: [AccountMeta; ADD_VALIDATOR_IX_ACCOUNTS_LEN] = keys.into();
     let args_full: AddValidatorIxArgs = args.into();
     let data: AddValidatorIxData = args_full.into();
-    Ok(Instruction {
-        program_id: crate::ID,
+    Instruction {
+        program_id,
         accounts: Vec::from(metas),
-        data: data.try_to_vec()?,
-    })
+        data: Vec::from(data.to_bytes()),
+    }
+}
+pub fn add_validator_ix_fixed<K: Into<AddValidatorKeys>, A: Into<AddValidatorIxArgs>>(
+    accounts: K,
+    args: A,
+) -> Instruction {
+    add_validator_ix_fixed_with_program_id(crate::ID, accounts, args)
 }
 pub fn add_validator_invoke<'info, A: Into<AddValidatorIxArgs>>(
     accounts: AddValidatorAccounts<'_, 'info>,
     args: A,
 ) -> ProgramResult {
-    let ix = add_validator_ix(accounts, args)?;
+    let ix = add_validator_ix_fixed(accounts, args);
     let account_info: [AccountInfo<'info>; ADD_VALIDATOR_IX_ACCOUNTS_LEN] = accounts.into();
     invoke(&ix, &account_info)
 }
@@ -890,10 +1077,58 @@ pub fn add_validator_invoke_signed<'info, A: Into<AddValidatorIxArgs>>(
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = add_validator_ix(accounts, args)?;
+    let ix = add_validator_ix_fixed(accounts, args);
+    let account_info: [AccountInfo<'info>; ADD_VALIDATOR_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn add_validator_invoke_with_program_id<'info, A: Into<AddValidatorIxArgs>>(
+    program_id: Pubkey,
+    accounts: AddValidatorAccounts<'_, 'info>,
+    args: A,
+) -> ProgramResult {
+    let ix = add_validator_ix_fixed_with_program_id(program_id, accounts, args);
+    let account_info: [AccountInfo<'info>; ADD_VALIDATOR_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn add_validator_invoke_signed_with_program_id<'info, A: Into<AddValidatorIxArgs>>(
+    program_id: Pubkey,
+    accounts: AddValidatorAccounts<'_, 'info>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = add_validator_ix_fixed_with_program_id(program_id, accounts, args);
     let account_info: [AccountInfo<'info>; ADD_VALIDATOR_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
+pub fn add_validator_invoke_with_slice<A: Into<AddValidatorIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+) -> ProgramResult {
+    let args_full: AddValidatorIxArgs = args.into();
+    let data: AddValidatorIxData = args_full.into();
+    let ix = ix_from_slice::<ADD_VALIDATOR_IX_ACCOUNTS_LEN, AddValidatorKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke(&ix, accounts)
+}
+pub fn add_validator_invoke_signed_with_slice<A: Into<AddValidatorIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let args_full: AddValidatorIxArgs = args.into();
+    let data: AddValidatorIxData = args_full.into();
+    let ix = ix_from_slice::<ADD_VALIDATOR_IX_ACCOUNTS_LEN, AddValidatorKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke_signed(&ix, accounts, seeds)
+}
 pub fn add_validator_verify_account_keys(
     accounts: AddValidatorAccounts<'_, '_>,
     keys: AddValidatorKeys,
@@ -1032,6 +1267,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_VALIDATOR_IX_ACCOUNTS_LEN
     }
 }
 pub const REMOVE_VALIDATOR_IX_DISCM: [u8; 8] = [25, 96, 211, 155, 161, 14, 168, 188];
+pub const REMOVE_VALIDATOR_IX_DATA_LEN: usize = 44;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RemoveValidatorIxArgs {
@@ -1070,26 +1306,61 @@ impl RemoveValidatorIxData {
         self.serialize(&mut data)?;
         Ok(data)
     }
+    pub fn to_bytes(&self) -> [u8; REMOVE_VALIDATOR_IX_DATA_LEN] {
+        let mut data = [0u8; REMOVE_VALIDATOR_IX_DATA_LEN];
+        data[..8].copy_from_slice(&REMOVE_VALIDATOR_IX_DISCM);
+        data[8..12].copy_from_slice(&self.0.index.to_le_bytes());
+        data[12..44].copy_from_slice(self.0.validator_vote.as_ref());
+        data
+    }
+}
+pub fn remove_validator_ix_with_program_id<
+    K: Into<RemoveValidatorKeys>,
+    A: Into<RemoveValidatorIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    Ok(remove_validator_ix_fixed_with_program_id(
+        program_id, accounts, args,
+    ))
 }
 pub fn remove_validator_ix<K: Into<RemoveValidatorKeys>, A: Into<RemoveValidatorIxArgs>>(
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
+    remove_validator_ix_with_program_id(crate::ID, accounts, args)
+}
+pub fn remove_validator_ix_fixed_with_program_id<
+    K: Into<RemoveValidatorKeys>,
+    A: Into<RemoveValidatorIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> Instruction {
     let keys: RemoveValidatorKeys = accounts.into();
     let metas: [AccountMeta; REMOVE_VALIDATOR_IX_ACCOUNTS_LEN] = keys.into();
     let args_full: RemoveValidatorIxArgs = args.into();
     let data: RemoveValidatorIxData = args_full.into();
-    Ok(Instruction {
-        program_id: crate::ID,
+    Instruction {
+        program_id,
         accounts: Vec::from(metas),
-        data: data.try_to_vec()?,
-    })
+        data: Vec::from(data.to_bytes()),
+    }
+}
+pub fn remove_validator_ix_fixed<K: Into<RemoveValidatorKeys>, A: Into<RemoveValidatorIxArgs>>(
+    accounts: K,
+    args: A,
+) -> Instruction {
+    remove_validator_ix_fixed_with_program_id(crate::ID, accounts, args)
 }
 pub fn remove_validator_invoke<'info, A: Into<RemoveValidatorIxArgs>>(
     accounts: RemoveValidatorAccounts<'_, 'info>,
     args: A,
 ) -> ProgramResult {
-    let ix = remove_validator_ix(accounts, args)?;
+    let ix = remove_validator_ix_fixed(accounts, args);
     let account_info: [AccountInfo<'info>; REMOVE_VALIDATOR_IX_ACCOUNTS_LEN] = accounts.into();
     invoke(&ix, &account_info)
 }
@@ -1098,10 +1369,58 @@ pub fn remove_validator_invoke_signed<'info, A: Into<RemoveValidatorIxArgs>>(
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = remove_validator_ix(accounts, args)?;
+    let ix = remove_validator_ix_fixed(accounts, args);
+    let account_info: [AccountInfo<'info>; REMOVE_VALIDATOR_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn remove_validator_invoke_with_program_id<'info, A: Into<RemoveValidatorIxArgs>>(
+    program_id: Pubkey,
+    accounts: RemoveValidatorAccounts<'_, 'info>,
+    args: A,
+) -> ProgramResult {
+    let ix = remove_validator_ix_fixed_with_program_id(program_id, accounts, args);
+    let account_info: [AccountInfo<'info>; REMOVE_VALIDATOR_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn remove_validator_invoke_signed_with_program_id<'info, A: Into<RemoveValidatorIxArgs>>(
+    program_id: Pubkey,
+    accounts: RemoveValidatorAccounts<'_, 'info>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = remove_validator_ix_fixed_with_program_id(program_id, accounts, args);
     let account_info: [AccountInfo<'info>; REMOVE_VALIDATOR_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
+pub fn remove_validator_invoke_with_slice<A: Into<RemoveValidatorIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+) -> ProgramResult {
+    let args_full: RemoveValidatorIxArgs = args.into();
+    let data: RemoveValidatorIxData = args_full.into();
+    let ix = ix_from_slice::<REMOVE_VALIDATOR_IX_ACCOUNTS_LEN, RemoveValidatorKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke(&ix, accounts)
+}
+pub fn remove_validator_invoke_signed_with_slice<A: Into<RemoveValidatorIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let args_full: RemoveValidatorIxArgs = args.into();
+    let data: RemoveValidatorIxData = args_full.into();
+    let ix = ix_from_slice::<REMOVE_VALIDATOR_IX_ACCOUNTS_LEN, RemoveValidatorKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke_signed(&ix, accounts, seeds)
+}
 pub fn remove_validator_verify_account_keys(
     accounts: RemoveValidatorAccounts<'_, '_>,
     keys: RemoveValidatorKeys,
@@ -1217,6 +1536,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; SET_VALIDATOR_SCORE_IX_ACCOUNTS_
     }
 }
 pub const SET_VALIDATOR_SCORE_IX_DISCM: [u8; 8] = [101, 41, 206, 33, 216, 111, 25, 78];
+pub const SET_VALIDATOR_SCORE_IX_DATA_LEN: usize = 48;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SetValidatorScoreIxArgs {
@@ -1256,26 +1576,65 @@ impl SetValidatorScoreIxData {
         self.serialize(&mut data)?;
         Ok(data)
     }
+    pub fn to_bytes(&self) -> [u8; SET_VALIDATOR_SCORE_IX_DATA_LEN] {
+        let mut data = [0u8; SET_VALIDATOR_SCORE_IX_DATA_LEN];
+        data[..8].copy_from_slice(&SET_VALIDATOR_SCORE_IX_DISCM);
+        data[8..12].copy_from_slice(&self.0.index.to_le_bytes());
+        data[12..44].copy_from_slice(self.0.validator_vote.as_ref());
+        data[44..48].copy_from_slice(&self.0.score.to_le_bytes());
+        data
+    }
+}
+pub fn set_validator_score_ix_with_program_id<
+    K: Into<SetValidatorScoreKeys>,
+    A: Into<SetValidatorScoreIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    Ok(set_validator_score_ix_fixed_with_program_id(
+        program_id, accounts, args,
+    ))
 }
 pub fn set_validator_score_ix<K: Into<SetValidatorScoreKeys>, A: Into<SetValidatorScoreIxArgs>>(
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
+    set_validator_score_ix_with_program_id(crate::ID, accounts, args)
+}
+pub fn set_validator_score_ix_fixed_with_program_id<
+    K: Into<SetValidatorScoreKeys>,
+    A: Into<SetValidatorScoreIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> Instruction {
     let keys: SetValidatorScoreKeys = accounts.into();
     let metas: [AccountMeta; SET_VALIDATOR_SCORE_IX_ACCOUNTS_LEN] = keys.into();
     let args_full: SetValidatorScoreIxArgs = args.into();
     let data: SetValidatorScoreIxData = args_full.into();
-    Ok(Instruction {
-        program_id: crate::ID,
+    Instruction {
+        program_id,
         accounts: Vec::from(metas),
-        data: data.try_to_vec()?,
-    })
+        data: Vec::from(data.to_bytes()),
+    }
+}
+pub fn set_validator_score_ix_fixed<
+    K: Into<SetValidatorScoreKeys>,
+    A: Into<SetValidatorScoreIxArgs>,
+>(
+    accounts: K,
+    args: A,
+) -> Instruction {
+    set_validator_score_ix_fixed_with_program_id(crate::ID, accounts, args)
 }
 pub fn set_validator_score_invoke<'info, A: Into<SetValidatorScoreIxArgs>>(
     accounts: SetValidatorScoreAccounts<'_, 'info>,
     args: A,
 ) -> ProgramResult {
-    let ix = set_validator_score_ix(accounts, args)?;
+    let ix = set_validator_score_ix_fixed(accounts, args);
     let account_info: [AccountInfo<'info>; SET_VALIDATOR_SCORE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke(&ix, &account_info)
 }
@@ -1284,10 +1643,61 @@ pub fn set_validator_score_invoke_signed<'info, A: Into<SetValidatorScoreIxArgs>
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = set_validator_score_ix(accounts, args)?;
+    let ix = set_validator_score_ix_fixed(accounts, args);
+    let account_info: [AccountInfo<'info>; SET_VALIDATOR_SCORE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn set_validator_score_invoke_with_program_id<'info, A: Into<SetValidatorScoreIxArgs>>(
+    program_id: Pubkey,
+    accounts: SetValidatorScoreAccounts<'_, 'info>,
+    args: A,
+) -> ProgramResult {
+    let ix = set_validator_score_ix_fixed_with_program_id(program_id, accounts, args);
+    let account_info: [AccountInfo<'info>; SET_VALIDATOR_SCORE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn set_validator_score_invoke_signed_with_program_id<
+    'info,
+    A: Into<SetValidatorScoreIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: SetValidatorScoreAccounts<'_, 'info>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = set_validator_score_ix_fixed_with_program_id(program_id, accounts, args);
     let account_info: [AccountInfo<'info>; SET_VALIDATOR_SCORE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
+pub fn set_validator_score_invoke_with_slice<A: Into<SetValidatorScoreIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+) -> ProgramResult {
+    let args_full: SetValidatorScoreIxArgs = args.into();
+    let data: SetValidatorScoreIxData = args_full.into();
+    let ix = ix_from_slice::<SET_VALIDATOR_SCORE_IX_ACCOUNTS_LEN, SetValidatorScoreKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke(&ix, accounts)
+}
+pub fn set_validator_score_invoke_signed_with_slice<A: Into<SetValidatorScoreIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let args_full: SetValidatorScoreIxArgs = args.into();
+    let data: SetValidatorScoreIxData = args_full.into();
+    let ix = ix_from_slice::<SET_VALIDATOR_SCORE_IX_ACCOUNTS_LEN, SetValidatorScoreKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke_signed(&ix, accounts, seeds)
+}
 pub fn set_validator_score_verify_account_keys(
     accounts: SetValidatorScoreAccounts<'_, '_>,
     keys: SetValidatorScoreKeys,
@@ -1379,6 +1789,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CONFIG_VALIDATOR_SYSTEM_IX_ACCOU
     }
 }
 pub const CONFIG_VALIDATOR_SYSTEM_IX_DISCM: [u8; 8] = [27, 90, 97, 209, 17, 115, 7, 40];
+pub const CONFIG_VALIDATOR_SYSTEM_IX_DATA_LEN: usize = 12;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConfigValidatorSystemIxArgs {
@@ -1416,6 +1827,24 @@ impl ConfigValidatorSystemIxData {
         self.serialize(&mut data)?;
         Ok(data)
     }
+    pub fn to_bytes(&self) -> [u8; CONFIG_VALIDATOR_SYSTEM_IX_DATA_LEN] {
+        let mut data = [0u8; CONFIG_VALIDATOR_SYSTEM_IX_DATA_LEN];
+        data[..8].copy_from_slice(&CONFIG_VALIDATOR_SYSTEM_IX_DISCM);
+        data[8..12].copy_from_slice(&self.0.extra_runs.to_le_bytes());
+        data
+    }
+}
+pub fn config_validator_system_ix_with_program_id<
+    K: Into<ConfigValidatorSystemKeys>,
+    A: Into<ConfigValidatorSystemIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    Ok(config_validator_system_ix_fixed_with_program_id(
+        program_id, accounts, args,
+    ))
 }
 pub fn config_validator_system_ix<
     K: Into<ConfigValidatorSystemKeys>,
@@ -1424,21 +1853,40 @@ pub fn config_validator_system_ix<
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
+    config_validator_system_ix_with_program_id(crate::ID, accounts, args)
+}
+pub fn config_validator_system_ix_fixed_with_program_id<
+    K: Into<ConfigValidatorSystemKeys>,
+    A: Into<ConfigValidatorSystemIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> Instruction {
     let keys: ConfigValidatorSystemKeys = accounts.into();
     let metas: [AccountMeta; CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN] = keys.into();
     let args_full: ConfigValidatorSystemIxArgs = args.into();
     let data: ConfigValidatorSystemIxData = args_full.into();
-    Ok(Instruction {
-        program_id: crate::ID,
+    Instruction {
+        program_id,
         accounts: Vec::from(metas),
-        data: data.try_to_vec()?,
-    })
+        data: Vec::from(data.to_bytes()),
+    }
+}
+pub fn config_validator_system_ix_fixed<
+    K: Into<ConfigValidatorSystemKeys>,
+    A: Into<ConfigValidatorSystemIxArgs>,
+>(
+    accounts: K,
+    args: A,
+) -> Instruction {
+    config_validator_system_ix_fixed_with_program_id(crate::ID, accounts, args)
 }
 pub fn config_validator_system_invoke<'info, A: Into<ConfigValidatorSystemIxArgs>>(
     accounts: ConfigValidatorSystemAccounts<'_, 'info>,
     args: A,
 ) -> ProgramResult {
-    let ix = config_validator_system_ix(accounts, args)?;
+    let ix = config_validator_system_ix_fixed(accounts, args);
     let account_info: [AccountInfo<'info>; CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN] =
         accounts.into();
     invoke(&ix, &account_info)
@@ -1448,11 +1896,67 @@ pub fn config_validator_system_invoke_signed<'info, A: Into<ConfigValidatorSyste
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = config_validator_system_ix(accounts, args)?;
+    let ix = config_validator_system_ix_fixed(accounts, args);
+    let account_info: [AccountInfo<'info>; CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN] =
+        accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn config_validator_system_invoke_with_program_id<
+    'info,
+    A: Into<ConfigValidatorSystemIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: ConfigValidatorSystemAccounts<'_, 'info>,
+    args: A,
+) -> ProgramResult {
+    let ix = config_validator_system_ix_fixed_with_program_id(program_id, accounts, args);
+    let account_info: [AccountInfo<'info>; CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN] =
+        accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn config_validator_system_invoke_signed_with_program_id<
+    'info,
+    A: Into<ConfigValidatorSystemIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: ConfigValidatorSystemAccounts<'_, 'info>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = config_validator_system_ix_fixed_with_program_id(program_id, accounts, args);
     let account_info: [AccountInfo<'info>; CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN] =
         accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
+pub fn config_validator_system_invoke_with_slice<A: Into<ConfigValidatorSystemIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+) -> ProgramResult {
+    let args_full: ConfigValidatorSystemIxArgs = args.into();
+    let data: ConfigValidatorSystemIxData = args_full.into();
+    let ix = ix_from_slice::<CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN, ConfigValidatorSystemKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke(&ix, accounts)
+}
+pub fn config_validator_system_invoke_signed_with_slice<A: Into<ConfigValidatorSystemIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let args_full: ConfigValidatorSystemIxArgs = args.into();
+    let data: ConfigValidatorSystemIxData = args_full.into();
+    let ix = ix_from_slice::<CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN, ConfigValidatorSystemKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke_signed(&ix, accounts, seeds)
+}
 pub fn config_validator_system_verify_account_keys(
     accounts: ConfigValidatorSystemAccounts<'_, '_>,
     keys: ConfigValidatorSystemKeys,
@@ -1643,6 +2147,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN]>
     }
 }
 pub const DEPOSIT_IX_DISCM: [u8; 8] = [242, 35, 198, 137, 82, 225, 242, 182];
+pub const DEPOSIT_IX_DATA_LEN: usize = 16;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DepositIxArgs {
@@ -1680,26 +2185,52 @@ impl DepositIxData {
         self.serialize(&mut data)?;
         Ok(data)
     }
+    pub fn to_bytes(&self) -> [u8; DEPOSIT_IX_DATA_LEN] {
+        let mut data = [0u8; DEPOSIT_IX_DATA_LEN];
+        data[..8].copy_from_slice(&DEPOSIT_IX_DISCM);
+        data[8..16].copy_from_slice(&self.0.lamports.to_le_bytes());
+        data
+    }
+}
+pub fn deposit_ix_with_program_id<K: Into<DepositKeys>, A: Into<DepositIxArgs>>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    Ok(deposit_ix_fixed_with_program_id(program_id, accounts, args))
 }
 pub fn deposit_ix<K: Into<DepositKeys>, A: Into<DepositIxArgs>>(
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
+    deposit_ix_with_program_id(crate::ID, accounts, args)
+}
+pub fn deposit_ix_fixed_with_program_id<K: Into<DepositKeys>, A: Into<DepositIxArgs>>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> Instruction {
     let keys: DepositKeys = accounts.into();
     let metas: [AccountMeta; DEPOSIT_IX_ACCOUNTS_LEN] = keys.into();
     let args_full: DepositIxArgs = args.into();
     let data: DepositIxData = args_full.into();
-    Ok(Instruction {
-        program_id: crate::ID,
+    Instruction {
+        program_id,
         accounts: Vec::from(metas),
-        data: data.try_to_vec()?,
-    })
+        data: Vec::from(data.to_bytes()),
+    }
+}
+pub fn deposit_ix_fixed<K: Into<DepositKeys>, A: Into<DepositIxArgs>>(
+    accounts: K,
+    args: A,
+) -> Instruction {
+    deposit_ix_fixed_with_program_id(crate::ID, accounts, args)
 }
 pub fn deposit_invoke<'info, A: Into<DepositIxArgs>>(
     accounts: DepositAccounts<'_, 'info>,
     args: A,
 ) -> ProgramResult {
-    let ix = deposit_ix(accounts, args)?;
+    let ix = deposit_ix_fixed(accounts, args);
     let account_info: [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN] = accounts.into();
     invoke(&ix, &account_info)
 }
@@ -1708,11 +2239,59 @@ pub fn deposit_invoke_signed<'info, A: Into<DepositIxArgs>>(
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = deposit_ix(accounts, args)?;
+    let ix = deposit_ix_fixed(accounts, args);
     let account_info: [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
-pub fn deposit_verify_account_keys(
+pub fn deposit_invoke_with_program_id<'info, A: Into<DepositIxArgs>>(
+    program_id: Pubkey,
+    accounts: DepositAccounts<'_, 'info>,
+    args: A,
+) -> ProgramResult {
+    let ix = deposit_ix_fixed_with_program_id(program_id, accounts, args);
+    let account_info: [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn deposit_invoke_signed_with_program_id<'info, A: Into<DepositIxArgs>>(
+    program_id: Pubkey,
+    accounts: DepositAccounts<'_, 'info>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = deposit_ix_fixed_with_program_id(program_id, accounts, args);
+    let account_info: [AccountInfo<'info>; DEPOSIT_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn deposit_invoke_with_slice<A: Into<DepositIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+) -> ProgramResult {
+    let args_full: DepositIxArgs = args.into();
+    let data: DepositIxData = args_full.into();
+    let ix = ix_from_slice::<DEPOSIT_IX_ACCOUNTS_LEN, DepositKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke(&ix, accounts)
+}
+pub fn deposit_invoke_signed_with_slice<A: Into<DepositIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let args_full: DepositIxArgs = args.into();
+    let data: DepositIxData = args_full.into();
+    let ix = ix_from_slice::<DEPOSIT_IX_ACCOUNTS_LEN, DepositKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke_signed(&ix, accounts, seeds)
+}
+pub fn deposit_verify_account_keys(
     accounts: DepositAccounts<'_, '_>,
     keys: DepositKeys,
 ) -> Result<(), (Pubkey, Pubkey)> {
@@ -1971,6 +2550,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNT
     }
 }
 pub const DEPOSIT_STAKE_ACCOUNT_IX_DISCM: [u8; 8] = [110, 130, 115, 41, 164, 102, 2, 59];
+pub const DEPOSIT_STAKE_ACCOUNT_IX_DATA_LEN: usize = 12;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DepositStakeAccountIxArgs {
@@ -2008,6 +2588,24 @@ impl DepositStakeAccountIxData {
         self.serialize(&mut data)?;
         Ok(data)
     }
+    pub fn to_bytes(&self) -> [u8; DEPOSIT_STAKE_ACCOUNT_IX_DATA_LEN] {
+        let mut data = [0u8; DEPOSIT_STAKE_ACCOUNT_IX_DATA_LEN];
+        data[..8].copy_from_slice(&DEPOSIT_STAKE_ACCOUNT_IX_DISCM);
+        data[8..12].copy_from_slice(&self.0.validator_index.to_le_bytes());
+        data
+    }
+}
+pub fn deposit_stake_account_ix_with_program_id<
+    K: Into<DepositStakeAccountKeys>,
+    A: Into<DepositStakeAccountIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    Ok(deposit_stake_account_ix_fixed_with_program_id(
+        program_id, accounts, args,
+    ))
 }
 pub fn deposit_stake_account_ix<
     K: Into<DepositStakeAccountKeys>,
@@ -2016,21 +2614,40 @@ pub fn deposit_stake_account_ix<
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
+    deposit_stake_account_ix_with_program_id(crate::ID, accounts, args)
+}
+pub fn deposit_stake_account_ix_fixed_with_program_id<
+    K: Into<DepositStakeAccountKeys>,
+    A: Into<DepositStakeAccountIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> Instruction {
     let keys: DepositStakeAccountKeys = accounts.into();
     let metas: [AccountMeta; DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = keys.into();
     let args_full: DepositStakeAccountIxArgs = args.into();
     let data: DepositStakeAccountIxData = args_full.into();
-    Ok(Instruction {
-        program_id: crate::ID,
+    Instruction {
+        program_id,
         accounts: Vec::from(metas),
-        data: data.try_to_vec()?,
-    })
+        data: Vec::from(data.to_bytes()),
+    }
+}
+pub fn deposit_stake_account_ix_fixed<
+    K: Into<DepositStakeAccountKeys>,
+    A: Into<DepositStakeAccountIxArgs>,
+>(
+    accounts: K,
+    args: A,
+) -> Instruction {
+    deposit_stake_account_ix_fixed_with_program_id(crate::ID, accounts, args)
 }
 pub fn deposit_stake_account_invoke<'info, A: Into<DepositStakeAccountIxArgs>>(
     accounts: DepositStakeAccountAccounts<'_, 'info>,
     args: A,
 ) -> ProgramResult {
-    let ix = deposit_stake_account_ix(accounts, args)?;
+    let ix = deposit_stake_account_ix_fixed(accounts, args);
     let account_info: [AccountInfo<'info>; DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = accounts.into();
     invoke(&ix, &account_info)
 }
@@ -2039,10 +2656,61 @@ pub fn deposit_stake_account_invoke_signed<'info, A: Into<DepositStakeAccountIxA
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = deposit_stake_account_ix(accounts, args)?;
+    let ix = deposit_stake_account_ix_fixed(accounts, args);
     let account_info: [AccountInfo<'info>; DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
+pub fn deposit_stake_account_invoke_with_program_id<'info, A: Into<DepositStakeAccountIxArgs>>(
+    program_id: Pubkey,
+    accounts: DepositStakeAccountAccounts<'_, 'info>,
+    args: A,
+) -> ProgramResult {
+    let ix = deposit_stake_account_ix_fixed_with_program_id(program_id, accounts, args);
+    let account_info: [AccountInfo<'info>; DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn deposit_stake_account_invoke_signed_with_program_id<
+    'info,
+    A: Into<DepositStakeAccountIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: DepositStakeAccountAccounts<'_, 'info>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = deposit_stake_account_ix_fixed_with_program_id(program_id, accounts, args);
+    let account_info: [AccountInfo<'info>; DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn deposit_stake_account_invoke_with_slice<A: Into<DepositStakeAccountIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+) -> ProgramResult {
+    let args_full: DepositStakeAccountIxArgs = args.into();
+    let data: DepositStakeAccountIxData = args_full.into();
+    let ix = ix_from_slice::<DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNTS_LEN, DepositStakeAccountKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke(&ix, accounts)
+}
+pub fn deposit_stake_account_invoke_signed_with_slice<A: Into<DepositStakeAccountIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let args_full: DepositStakeAccountIxArgs = args.into();
+    let data: DepositStakeAccountIxData = args_full.into();
+    let ix = ix_from_slice::<DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNTS_LEN, DepositStakeAccountKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke_signed(&ix, accounts, seeds)
+}
 pub fn deposit_stake_account_verify_account_keys(
     accounts: DepositStakeAccountAccounts<'_, '_>,
     keys: DepositStakeAccountKeys,
@@ -2246,6 +2914,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; LIQUID_UNSTAKE_IX_ACCOUNTS_LEN]>
     }
 }
 pub const LIQUID_UNSTAKE_IX_DISCM: [u8; 8] = [30, 30, 119, 240, 191, 227, 12, 16];
+pub const LIQUID_UNSTAKE_IX_DATA_LEN: usize = 16;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LiquidUnstakeIxArgs {
@@ -2283,26 +2952,60 @@ impl LiquidUnstakeIxData {
         self.serialize(&mut data)?;
         Ok(data)
     }
+    pub fn to_bytes(&self) -> [u8; LIQUID_UNSTAKE_IX_DATA_LEN] {
+        let mut data = [0u8; LIQUID_UNSTAKE_IX_DATA_LEN];
+        data[..8].copy_from_slice(&LIQUID_UNSTAKE_IX_DISCM);
+        data[8..16].copy_from_slice(&self.0.msol_amount.to_le_bytes());
+        data
+    }
+}
+pub fn liquid_unstake_ix_with_program_id<
+    K: Into<LiquidUnstakeKeys>,
+    A: Into<LiquidUnstakeIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    Ok(liquid_unstake_ix_fixed_with_program_id(
+        program_id, accounts, args,
+    ))
 }
 pub fn liquid_unstake_ix<K: Into<LiquidUnstakeKeys>, A: Into<LiquidUnstakeIxArgs>>(
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
+    liquid_unstake_ix_with_program_id(crate::ID, accounts, args)
+}
+pub fn liquid_unstake_ix_fixed_with_program_id<
+    K: Into<LiquidUnstakeKeys>,
+    A: Into<LiquidUnstakeIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> Instruction {
     let keys: LiquidUnstakeKeys = accounts.into();
     let metas: [AccountMeta; LIQUID_UNSTAKE_IX_ACCOUNTS_LEN] = keys.into();
     let args_full: LiquidUnstakeIxArgs = args.into();
     let data: LiquidUnstakeIxData = args_full.into();
-    Ok(Instruction {
-        program_id: crate::ID,
+    Instruction {
+        program_id,
         accounts: Vec::from(metas),
-        data: data.try_to_vec()?,
-    })
+        data: Vec::from(data.to_bytes()),
+    }
+}
+pub fn liquid_unstake_ix_fixed<K: Into<LiquidUnstakeKeys>, A: Into<LiquidUnstakeIxArgs>>(
+    accounts: K,
+    args: A,
+) -> Instruction {
+    liquid_unstake_ix_fixed_with_program_id(crate::ID, accounts, args)
 }
 pub fn liquid_unstake_invoke<'info, A: Into<LiquidUnstakeIxArgs>>(
     accounts: LiquidUnstakeAccounts<'_, 'info>,
     args: A,
 ) -> ProgramResult {
-    let ix = liquid_unstake_ix(accounts, args)?;
+    let ix = liquid_unstake_ix_fixed(accounts, args);
     let account_info: [AccountInfo<'info>; LIQUID_UNSTAKE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke(&ix, &account_info)
 }
@@ -2311,10 +3014,58 @@ pub fn liquid_unstake_invoke_signed<'info, A: Into<LiquidUnstakeIxArgs>>(
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = liquid_unstake_ix(accounts, args)?;
+    let ix = liquid_unstake_ix_fixed(accounts, args);
+    let account_info: [AccountInfo<'info>; LIQUID_UNSTAKE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn liquid_unstake_invoke_with_program_id<'info, A: Into<LiquidUnstakeIxArgs>>(
+    program_id: Pubkey,
+    accounts: LiquidUnstakeAccounts<'_, 'info>,
+    args: A,
+) -> ProgramResult {
+    let ix = liquid_unstake_ix_fixed_with_program_id(program_id, accounts, args);
+    let account_info: [AccountInfo<'info>; LIQUID_UNSTAKE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn liquid_unstake_invoke_signed_with_program_id<'info, A: Into<LiquidUnstakeIxArgs>>(
+    program_id: Pubkey,
+    accounts: LiquidUnstakeAccounts<'_, 'info>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = liquid_unstake_ix_fixed_with_program_id(program_id, accounts, args);
     let account_info: [AccountInfo<'info>; LIQUID_UNSTAKE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
+pub fn liquid_unstake_invoke_with_slice<A: Into<LiquidUnstakeIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+) -> ProgramResult {
+    let args_full: LiquidUnstakeIxArgs = args.into();
+    let data: LiquidUnstakeIxData = args_full.into();
+    let ix = ix_from_slice::<LIQUID_UNSTAKE_IX_ACCOUNTS_LEN, LiquidUnstakeKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke(&ix, accounts)
+}
+pub fn liquid_unstake_invoke_signed_with_slice<A: Into<LiquidUnstakeIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let args_full: LiquidUnstakeIxArgs = args.into();
+    let data: LiquidUnstakeIxData = args_full.into();
+    let ix = ix_from_slice::<LIQUID_UNSTAKE_IX_ACCOUNTS_LEN, LiquidUnstakeKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke_signed(&ix, accounts, seeds)
+}
 pub fn liquid_unstake_verify_account_keys(
     accounts: LiquidUnstakeAccounts<'_, '_>,
     keys: LiquidUnstakeKeys,
@@ -2510,6 +3261,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; ADD_LIQUIDITY_IX_ACCOUNTS_LEN]>
     }
 }
 pub const ADD_LIQUIDITY_IX_DISCM: [u8; 8] = [181, 157, 89, 67, 143, 182, 52, 72];
+pub const ADD_LIQUIDITY_IX_DATA_LEN: usize = 16;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AddLiquidityIxArgs {
@@ -2547,26 +3299,57 @@ impl AddLiquidityIxData {
         self.serialize(&mut data)?;
         Ok(data)
     }
+    pub fn to_bytes(&self) -> [u8; ADD_LIQUIDITY_IX_DATA_LEN] {
+        let mut data = [0u8; ADD_LIQUIDITY_IX_DATA_LEN];
+        data[..8].copy_from_slice(&ADD_LIQUIDITY_IX_DISCM);
+        data[8..16].copy_from_slice(&self.0.lamports.to_le_bytes());
+        data
+    }
+}
+pub fn add_liquidity_ix_with_program_id<K: Into<AddLiquidityKeys>, A: Into<AddLiquidityIxArgs>>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    Ok(add_liquidity_ix_fixed_with_program_id(
+        program_id, accounts, args,
+    ))
 }
 pub fn add_liquidity_ix<K: Into<AddLiquidityKeys>, A: Into<AddLiquidityIxArgs>>(
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
+    add_liquidity_ix_with_program_id(crate::ID, accounts, args)
+}
+pub fn add_liquidity_ix_fixed_with_program_id<
+    K: Into<AddLiquidityKeys>,
+    A: Into<AddLiquidityIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> Instruction {
     let keys: AddLiquidityKeys = accounts.into();
     let metas: [AccountMeta; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] = keys.into();
     let args_full: AddLiquidityIxArgs = args.into();
     let data: AddLiquidityIxData = args_full.into();
-    Ok(Instruction {
-        program_id: crate::ID,
+    Instruction {
+        program_id,
         accounts: Vec::from(metas),
-        data: data.try_to_vec()?,
-    })
+        data: Vec::from(data.to_bytes()),
+    }
+}
+pub fn add_liquidity_ix_fixed<K: Into<AddLiquidityKeys>, A: Into<AddLiquidityIxArgs>>(
+    accounts: K,
+    args: A,
+) -> Instruction {
+    add_liquidity_ix_fixed_with_program_id(crate::ID, accounts, args)
 }
 pub fn add_liquidity_invoke<'info, A: Into<AddLiquidityIxArgs>>(
     accounts: AddLiquidityAccounts<'_, 'info>,
     args: A,
 ) -> ProgramResult {
-    let ix = add_liquidity_ix(accounts, args)?;
+    let ix = add_liquidity_ix_fixed(accounts, args);
     let account_info: [AccountInfo<'info>; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] = accounts.into();
     invoke(&ix, &account_info)
 }
@@ -2575,10 +3358,58 @@ pub fn add_liquidity_invoke_signed<'info, A: Into<AddLiquidityIxArgs>>(
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = add_liquidity_ix(accounts, args)?;
+    let ix = add_liquidity_ix_fixed(accounts, args);
+    let account_info: [AccountInfo<'info>; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn add_liquidity_invoke_with_program_id<'info, A: Into<AddLiquidityIxArgs>>(
+    program_id: Pubkey,
+    accounts: AddLiquidityAccounts<'_, 'info>,
+    args: A,
+) -> ProgramResult {
+    let ix = add_liquidity_ix_fixed_with_program_id(program_id, accounts, args);
+    let account_info: [AccountInfo<'info>; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn add_liquidity_invoke_signed_with_program_id<'info, A: Into<AddLiquidityIxArgs>>(
+    program_id: Pubkey,
+    accounts: AddLiquidityAccounts<'_, 'info>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = add_liquidity_ix_fixed_with_program_id(program_id, accounts, args);
     let account_info: [AccountInfo<'info>; ADD_LIQUIDITY_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
+pub fn add_liquidity_invoke_with_slice<A: Into<AddLiquidityIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+) -> ProgramResult {
+    let args_full: AddLiquidityIxArgs = args.into();
+    let data: AddLiquidityIxData = args_full.into();
+    let ix = ix_from_slice::<ADD_LIQUIDITY_IX_ACCOUNTS_LEN, AddLiquidityKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke(&ix, accounts)
+}
+pub fn add_liquidity_invoke_signed_with_slice<A: Into<AddLiquidityIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let args_full: AddLiquidityIxArgs = args.into();
+    let data: AddLiquidityIxData = args_full.into();
+    let ix = ix_from_slice::<ADD_LIQUIDITY_IX_ACCOUNTS_LEN, AddLiquidityKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke_signed(&ix, accounts, seeds)
+}
 pub fn add_liquidity_verify_account_keys(
     accounts: AddLiquidityAccounts<'_, '_>,
     keys: AddLiquidityKeys,
@@ -2787,6 +3618,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN
     }
 }
 pub const REMOVE_LIQUIDITY_IX_DISCM: [u8; 8] = [80, 85, 209, 72, 24, 206, 177, 108];
+pub const REMOVE_LIQUIDITY_IX_DATA_LEN: usize = 16;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RemoveLiquidityIxArgs {
@@ -2824,26 +3656,60 @@ impl RemoveLiquidityIxData {
         self.serialize(&mut data)?;
         Ok(data)
     }
+    pub fn to_bytes(&self) -> [u8; REMOVE_LIQUIDITY_IX_DATA_LEN] {
+        let mut data = [0u8; REMOVE_LIQUIDITY_IX_DATA_LEN];
+        data[..8].copy_from_slice(&REMOVE_LIQUIDITY_IX_DISCM);
+        data[8..16].copy_from_slice(&self.0.tokens.to_le_bytes());
+        data
+    }
+}
+pub fn remove_liquidity_ix_with_program_id<
+    K: Into<RemoveLiquidityKeys>,
+    A: Into<RemoveLiquidityIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    Ok(remove_liquidity_ix_fixed_with_program_id(
+        program_id, accounts, args,
+    ))
 }
 pub fn remove_liquidity_ix<K: Into<RemoveLiquidityKeys>, A: Into<RemoveLiquidityIxArgs>>(
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
+    remove_liquidity_ix_with_program_id(crate::ID, accounts, args)
+}
+pub fn remove_liquidity_ix_fixed_with_program_id<
+    K: Into<RemoveLiquidityKeys>,
+    A: Into<RemoveLiquidityIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> Instruction {
     let keys: RemoveLiquidityKeys = accounts.into();
     let metas: [AccountMeta; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN] = keys.into();
     let args_full: RemoveLiquidityIxArgs = args.into();
     let data: RemoveLiquidityIxData = args_full.into();
-    Ok(Instruction {
-        program_id: crate::ID,
+    Instruction {
+        program_id,
         accounts: Vec::from(metas),
-        data: data.try_to_vec()?,
-    })
+        data: Vec::from(data.to_bytes()),
+    }
+}
+pub fn remove_liquidity_ix_fixed<K: Into<RemoveLiquidityKeys>, A: Into<RemoveLiquidityIxArgs>>(
+    accounts: K,
+    args: A,
+) -> Instruction {
+    remove_liquidity_ix_fixed_with_program_id(crate::ID, accounts, args)
 }
 pub fn remove_liquidity_invoke<'info, A: Into<RemoveLiquidityIxArgs>>(
     accounts: RemoveLiquidityAccounts<'_, 'info>,
     args: A,
 ) -> ProgramResult {
-    let ix = remove_liquidity_ix(accounts, args)?;
+    let ix = remove_liquidity_ix_fixed(accounts, args);
     let account_info: [AccountInfo<'info>; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN] = accounts.into();
     invoke(&ix, &account_info)
 }
@@ -2852,10 +3718,58 @@ pub fn remove_liquidity_invoke_signed<'info, A: Into<RemoveLiquidityIxArgs>>(
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = remove_liquidity_ix(accounts, args)?;
+    let ix = remove_liquidity_ix_fixed(accounts, args);
+    let account_info: [AccountInfo<'info>; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn remove_liquidity_invoke_with_program_id<'info, A: Into<RemoveLiquidityIxArgs>>(
+    program_id: Pubkey,
+    accounts: RemoveLiquidityAccounts<'_, 'info>,
+    args: A,
+) -> ProgramResult {
+    let ix = remove_liquidity_ix_fixed_with_program_id(program_id, accounts, args);
+    let account_info: [AccountInfo<'info>; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn remove_liquidity_invoke_signed_with_program_id<'info, A: Into<RemoveLiquidityIxArgs>>(
+    program_id: Pubkey,
+    accounts: RemoveLiquidityAccounts<'_, 'info>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = remove_liquidity_ix_fixed_with_program_id(program_id, accounts, args);
     let account_info: [AccountInfo<'info>; REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
+pub fn remove_liquidity_invoke_with_slice<A: Into<RemoveLiquidityIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+) -> ProgramResult {
+    let args_full: RemoveLiquidityIxArgs = args.into();
+    let data: RemoveLiquidityIxData = args_full.into();
+    let ix = ix_from_slice::<REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN, RemoveLiquidityKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke(&ix, accounts)
+}
+pub fn remove_liquidity_invoke_signed_with_slice<A: Into<RemoveLiquidityIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let args_full: RemoveLiquidityIxArgs = args.into();
+    let data: RemoveLiquidityIxData = args_full.into();
+    let ix = ix_from_slice::<REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN, RemoveLiquidityKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke_signed(&ix, accounts, seeds)
+}
 pub fn remove_liquidity_verify_account_keys(
     accounts: RemoveLiquidityAccounts<'_, '_>,
     keys: RemoveLiquidityKeys,
@@ -3005,7 +3919,8 @@ impl ConfigLpIxData {
         Ok(data)
     }
 }
-pub fn config_lp_ix<K: Into<ConfigLpKeys>, A: Into<ConfigLpIxArgs>>(
+pub fn config_lp_ix_with_program_id<K: Into<ConfigLpKeys>, A: Into<ConfigLpIxArgs>>(
+    program_id: Pubkey,
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
@@ -3014,11 +3929,17 @@ pub fn config_lp_ix<K: Into<ConfigLpKeys>, A: Into<ConfigLpIxArgs>>(
     let args_full: ConfigLpIxArgs = args.into();
     let data: ConfigLpIxData = args_full.into();
     Ok(Instruction {
-        program_id: crate::ID,
+        program_id,
         accounts: Vec::from(metas),
         data: data.try_to_vec()?,
     })
 }
+pub fn config_lp_ix<K: Into<ConfigLpKeys>, A: Into<ConfigLpIxArgs>>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    config_lp_ix_with_program_id(crate::ID, accounts, args)
+}
 pub fn config_lp_invoke<'info, A: Into<ConfigLpIxArgs>>(
     accounts: ConfigLpAccounts<'_, 'info>,
     args: A,
@@ -3036,6 +3957,54 @@ pub fn config_lp_invoke_signed<'info, A: Into<ConfigLpIxArgs>>(
     let account_info: [AccountInfo<'info>; CONFIG_LP_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
+pub fn config_lp_invoke_with_program_id<'info, A: Into<ConfigLpIxArgs>>(
+    program_id: Pubkey,
+    accounts: ConfigLpAccounts<'_, 'info>,
+    args: A,
+) -> ProgramResult {
+    let ix = config_lp_ix_with_program_id(program_id, accounts, args)?;
+    let account_info: [AccountInfo<'info>; CONFIG_LP_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn config_lp_invoke_signed_with_program_id<'info, A: Into<ConfigLpIxArgs>>(
+    program_id: Pubkey,
+    accounts: ConfigLpAccounts<'_, 'info>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = config_lp_ix_with_program_id(program_id, accounts, args)?;
+    let account_info: [AccountInfo<'info>; CONFIG_LP_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn config_lp_invoke_with_slice<A: Into<ConfigLpIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+) -> ProgramResult {
+    let args_full: ConfigLpIxArgs = args.into();
+    let data: ConfigLpIxData = args_full.into();
+    let ix = ix_from_slice::<CONFIG_LP_IX_ACCOUNTS_LEN, ConfigLpKeys>(
+        program_id,
+        accounts,
+        data.try_to_vec()?,
+    )?;
+    invoke(&ix, accounts)
+}
+pub fn config_lp_invoke_signed_with_slice<A: Into<ConfigLpIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let args_full: ConfigLpIxArgs = args.into();
+    let data: ConfigLpIxData = args_full.into();
+    let ix = ix_from_slice::<CONFIG_LP_IX_ACCOUNTS_LEN, ConfigLpKeys>(
+        program_id,
+        accounts,
+        data.try_to_vec()?,
+    )?;
+    invoke_signed(&ix, accounts, seeds)
+}
 pub fn config_lp_verify_account_keys(
     accounts: ConfigLpAccounts<'_, '_>,
     keys: ConfigLpKeys,
@@ -3164,7 +4133,11 @@ impl ConfigMarinadeIxData {
         Ok(data)
     }
 }
-pub fn config_marinade_ix<K: Into<ConfigMarinadeKeys>, A: Into<ConfigMarinadeIxArgs>>(
+pub fn config_marinade_ix_with_program_id<
+    K: Into<ConfigMarinadeKeys>,
+    A: Into<ConfigMarinadeIxArgs>,
+>(
+    program_id: Pubkey,
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
@@ -3173,11 +4146,17 @@ pub fn config_marinade_ix<K: Into<ConfigMarinadeKeys>, A: Into<ConfigMarinadeIxA
     let args_full: ConfigMarinadeIxArgs = args.into();
     let data: ConfigMarinadeIxData = args_full.into();
     Ok(Instruction {
-        program_id: crate::ID,
+        program_id,
         accounts: Vec::from(metas),
         data: data.try_to_vec()?,
     })
 }
+pub fn config_marinade_ix<K: Into<ConfigMarinadeKeys>, A: Into<ConfigMarinadeIxArgs>>(
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    config_marinade_ix_with_program_id(crate::ID, accounts, args)
+}
 pub fn config_marinade_invoke<'info, A: Into<ConfigMarinadeIxArgs>>(
     accounts: ConfigMarinadeAccounts<'_, 'info>,
     args: A,
@@ -3195,6 +4174,54 @@ pub fn config_marinade_invoke_signed<'info, A: Into<ConfigMarinadeIxArgs>>(
     let account_info: [AccountInfo<'info>; CONFIG_MARINADE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
+pub fn config_marinade_invoke_with_program_id<'info, A: Into<ConfigMarinadeIxArgs>>(
+    program_id: Pubkey,
+    accounts: ConfigMarinadeAccounts<'_, 'info>,
+    args: A,
+) -> ProgramResult {
+    let ix = config_marinade_ix_with_program_id(program_id, accounts, args)?;
+    let account_info: [AccountInfo<'info>; CONFIG_MARINADE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn config_marinade_invoke_signed_with_program_id<'info, A: Into<ConfigMarinadeIxArgs>>(
+    program_id: Pubkey,
+    accounts: ConfigMarinadeAccounts<'_, 'info>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = config_marinade_ix_with_program_id(program_id, accounts, args)?;
+    let account_info: [AccountInfo<'info>; CONFIG_MARINADE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn config_marinade_invoke_with_slice<A: Into<ConfigMarinadeIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+) -> ProgramResult {
+    let args_full: ConfigMarinadeIxArgs = args.into();
+    let data: ConfigMarinadeIxData = args_full.into();
+    let ix = ix_from_slice::<CONFIG_MARINADE_IX_ACCOUNTS_LEN, ConfigMarinadeKeys>(
+        program_id,
+        accounts,
+        data.try_to_vec()?,
+    )?;
+    invoke(&ix, accounts)
+}
+pub fn config_marinade_invoke_signed_with_slice<A: Into<ConfigMarinadeIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let args_full: ConfigMarinadeIxArgs = args.into();
+    let data: ConfigMarinadeIxData = args_full.into();
+    let ix = ix_from_slice::<CONFIG_MARINADE_IX_ACCOUNTS_LEN, ConfigMarinadeKeys>(
+        program_id,
+        accounts,
+        data.try_to_vec()?,
+    )?;
+    invoke_signed(&ix, accounts, seeds)
+}
 pub fn config_marinade_verify_account_keys(
     accounts: ConfigMarinadeAccounts<'_, '_>,
     keys: ConfigMarinadeKeys,
@@ -3354,6 +4381,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; ORDER_UNSTAKE_IX_ACCOUNTS_LEN]>
     }
 }
 pub const ORDER_UNSTAKE_IX_DISCM: [u8; 8] = [97, 167, 144, 107, 117, 190, 128, 36];
+pub const ORDER_UNSTAKE_IX_DATA_LEN: usize = 16;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OrderUnstakeIxArgs {
@@ -3391,26 +4419,57 @@ impl OrderUnstakeIxData {
         self.serialize(&mut data)?;
         Ok(data)
     }
+    pub fn to_bytes(&self) -> [u8; ORDER_UNSTAKE_IX_DATA_LEN] {
+        let mut data = [0u8; ORDER_UNSTAKE_IX_DATA_LEN];
+        data[..8].copy_from_slice(&ORDER_UNSTAKE_IX_DISCM);
+        data[8..16].copy_from_slice(&self.0.msol_amount.to_le_bytes());
+        data
+    }
+}
+pub fn order_unstake_ix_with_program_id<K: Into<OrderUnstakeKeys>, A: Into<OrderUnstakeIxArgs>>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    Ok(order_unstake_ix_fixed_with_program_id(
+        program_id, accounts, args,
+    ))
 }
 pub fn order_unstake_ix<K: Into<OrderUnstakeKeys>, A: Into<OrderUnstakeIxArgs>>(
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
+    order_unstake_ix_with_program_id(crate::ID, accounts, args)
+}
+pub fn order_unstake_ix_fixed_with_program_id<
+    K: Into<OrderUnstakeKeys>,
+    A: Into<OrderUnstakeIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> Instruction {
     let keys: OrderUnstakeKeys = accounts.into();
     let metas: [AccountMeta; ORDER_UNSTAKE_IX_ACCOUNTS_LEN] = keys.into();
     let args_full: OrderUnstakeIxArgs = args.into();
     let data: OrderUnstakeIxData = args_full.into();
-    Ok(Instruction {
-        program_id: crate::ID,
+    Instruction {
+        program_id,
         accounts: Vec::from(metas),
-        data: data.try_to_vec()?,
-    })
+        data: Vec::from(data.to_bytes()),
+    }
+}
+pub fn order_unstake_ix_fixed<K: Into<OrderUnstakeKeys>, A: Into<OrderUnstakeIxArgs>>(
+    accounts: K,
+    args: A,
+) -> Instruction {
+    order_unstake_ix_fixed_with_program_id(crate::ID, accounts, args)
 }
 pub fn order_unstake_invoke<'info, A: Into<OrderUnstakeIxArgs>>(
     accounts: OrderUnstakeAccounts<'_, 'info>,
     args: A,
 ) -> ProgramResult {
-    let ix = order_unstake_ix(accounts, args)?;
+    let ix = order_unstake_ix_fixed(accounts, args);
     let account_info: [AccountInfo<'info>; ORDER_UNSTAKE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke(&ix, &account_info)
 }
@@ -3419,10 +4478,58 @@ pub fn order_unstake_invoke_signed<'info, A: Into<OrderUnstakeIxArgs>>(
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = order_unstake_ix(accounts, args)?;
+    let ix = order_unstake_ix_fixed(accounts, args);
+    let account_info: [AccountInfo<'info>; ORDER_UNSTAKE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn order_unstake_invoke_with_program_id<'info, A: Into<OrderUnstakeIxArgs>>(
+    program_id: Pubkey,
+    accounts: OrderUnstakeAccounts<'_, 'info>,
+    args: A,
+) -> ProgramResult {
+    let ix = order_unstake_ix_fixed_with_program_id(program_id, accounts, args);
+    let account_info: [AccountInfo<'info>; ORDER_UNSTAKE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn order_unstake_invoke_signed_with_program_id<'info, A: Into<OrderUnstakeIxArgs>>(
+    program_id: Pubkey,
+    accounts: OrderUnstakeAccounts<'_, 'info>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = order_unstake_ix_fixed_with_program_id(program_id, accounts, args);
     let account_info: [AccountInfo<'info>; ORDER_UNSTAKE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
+pub fn order_unstake_invoke_with_slice<A: Into<OrderUnstakeIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+) -> ProgramResult {
+    let args_full: OrderUnstakeIxArgs = args.into();
+    let data: OrderUnstakeIxData = args_full.into();
+    let ix = ix_from_slice::<ORDER_UNSTAKE_IX_ACCOUNTS_LEN, OrderUnstakeKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke(&ix, accounts)
+}
+pub fn order_unstake_invoke_signed_with_slice<A: Into<OrderUnstakeIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let args_full: OrderUnstakeIxArgs = args.into();
+    let data: OrderUnstakeIxData = args_full.into();
+    let ix = ix_from_slice::<ORDER_UNSTAKE_IX_ACCOUNTS_LEN, OrderUnstakeKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke_signed(&ix, accounts, seeds)
+}
 pub fn order_unstake_verify_account_keys(
     accounts: OrderUnstakeAccounts<'_, '_>,
     keys: OrderUnstakeKeys,
@@ -3569,6 +4676,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; CLAIM_IX_ACCOUNTS_LEN]>
     }
 }
 pub const CLAIM_IX_DISCM: [u8; 8] = [62, 198, 214, 193, 213, 159, 108, 210];
+pub const CLAIM_IX_DATA_LEN: usize = 8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct ClaimIxData;
 impl ClaimIxData {
@@ -3595,18 +4703,36 @@ impl ClaimIxData {
         self.serialize(&mut data)?;
         Ok(data)
     }
+    pub fn to_bytes(&self) -> [u8; CLAIM_IX_DATA_LEN] {
+        CLAIM_IX_DISCM
+    }
+}
+pub fn claim_ix_with_program_id<K: Into<ClaimKeys>>(
+    program_id: Pubkey,
+    accounts: K,
+) -> std::io::Result<Instruction> {
+    Ok(claim_ix_fixed_with_program_id(program_id, accounts))
 }
 pub fn claim_ix<K: Into<ClaimKeys>>(accounts: K) -> std::io::Result<Instruction> {
+    claim_ix_with_program_id(crate::ID, accounts)
+}
+pub fn claim_ix_fixed_with_program_id<K: Into<ClaimKeys>>(
+    program_id: Pubkey,
+    accounts: K,
+) -> Instruction {
     let keys: ClaimKeys = accounts.into();
     let metas: [AccountMeta; CLAIM_IX_ACCOUNTS_LEN] = keys.into();
-    Ok(Instruction {
-        program_id: crate::ID,
+    Instruction {
+        program_id,
         accounts: Vec::from(metas),
-        data: ClaimIxData.try_to_vec()?,
-    })
+        data: Vec::from(ClaimIxData.to_bytes()),
+    }
+}
+pub fn claim_ix_fixed<K: Into<ClaimKeys>>(accounts: K) -> Instruction {
+    claim_ix_fixed_with_program_id(crate::ID, accounts)
 }
 pub fn claim_invoke<'info>(accounts: ClaimAccounts<'_, 'info>) -> ProgramResult {
-    let ix = claim_ix(accounts)?;
+    let ix = claim_ix_fixed(accounts);
     let account_info: [AccountInfo<'info>; CLAIM_IX_ACCOUNTS_LEN] = accounts.into();
     invoke(&ix, &account_info)
 }
@@ -3614,10 +4740,47 @@ pub fn claim_invoke_signed<'info>(
     accounts: ClaimAccounts<'_, 'info>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = claim_ix(accounts)?;
+    let ix = claim_ix_fixed(accounts);
+    let account_info: [AccountInfo<'info>; CLAIM_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn claim_invoke_with_program_id<'info>(
+    program_id: Pubkey,
+    accounts: ClaimAccounts<'_, 'info>,
+) -> ProgramResult {
+    let ix = claim_ix_fixed_with_program_id(program_id, accounts);
+    let account_info: [AccountInfo<'info>; CLAIM_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn claim_invoke_signed_with_program_id<'info>(
+    program_id: Pubkey,
+    accounts: ClaimAccounts<'_, 'info>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = claim_ix_fixed_with_program_id(program_id, accounts);
     let account_info: [AccountInfo<'info>; CLAIM_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
+pub fn claim_invoke_with_slice(program_id: Pubkey, accounts: &[AccountInfo<'_>]) -> ProgramResult {
+    let ix = ix_from_slice::<CLAIM_IX_ACCOUNTS_LEN, ClaimKeys>(
+        program_id,
+        accounts,
+        Vec::from(ClaimIxData.to_bytes()),
+    )?;
+    invoke(&ix, accounts)
+}
+pub fn claim_invoke_signed_with_slice(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = ix_from_slice::<CLAIM_IX_ACCOUNTS_LEN, ClaimKeys>(
+        program_id,
+        accounts,
+        Vec::from(ClaimIxData.to_bytes()),
+    )?;
+    invoke_signed(&ix, accounts, seeds)
+}
 pub fn claim_verify_account_keys(
     accounts: ClaimAccounts<'_, '_>,
     keys: ClaimKeys,
@@ -3858,6 +5021,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; STAKE_RESERVE_IX_ACCOUNTS_LEN]>
     }
 }
 pub const STAKE_RESERVE_IX_DISCM: [u8; 8] = [87, 217, 23, 179, 205, 25, 113, 129];
+pub const STAKE_RESERVE_IX_DATA_LEN: usize = 12;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StakeReserveIxArgs {
@@ -3895,26 +5059,57 @@ impl StakeReserveIxData {
         self.serialize(&mut data)?;
         Ok(data)
     }
+    pub fn to_bytes(&self) -> [u8; STAKE_RESERVE_IX_DATA_LEN] {
+        let mut data = [0u8; STAKE_RESERVE_IX_DATA_LEN];
+        data[..8].copy_from_slice(&STAKE_RESERVE_IX_DISCM);
+        data[8..12].copy_from_slice(&self.0.validator_index.to_le_bytes());
+        data
+    }
+}
+pub fn stake_reserve_ix_with_program_id<K: Into<StakeReserveKeys>, A: Into<StakeReserveIxArgs>>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    Ok(stake_reserve_ix_fixed_with_program_id(
+        program_id, accounts, args,
+    ))
 }
 pub fn stake_reserve_ix<K: Into<StakeReserveKeys>, A: Into<StakeReserveIxArgs>>(
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
+    stake_reserve_ix_with_program_id(crate::ID, accounts, args)
+}
+pub fn stake_reserve_ix_fixed_with_program_id<
+    K: Into<StakeReserveKeys>,
+    A: Into<StakeReserveIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> Instruction {
     let keys: StakeReserveKeys = accounts.into();
     let metas: [AccountMeta; STAKE_RESERVE_IX_ACCOUNTS_LEN] = keys.into();
     let args_full: StakeReserveIxArgs = args.into();
     let data: StakeReserveIxData = args_full.into();
-    Ok(Instruction {
-        program_id: crate::ID,
+    Instruction {
+        program_id,
         accounts: Vec::from(metas),
-        data: data.try_to_vec()?,
-    })
+        data: Vec::from(data.to_bytes()),
+    }
+}
+pub fn stake_reserve_ix_fixed<K: Into<StakeReserveKeys>, A: Into<StakeReserveIxArgs>>(
+    accounts: K,
+    args: A,
+) -> Instruction {
+    stake_reserve_ix_fixed_with_program_id(crate::ID, accounts, args)
 }
 pub fn stake_reserve_invoke<'info, A: Into<StakeReserveIxArgs>>(
     accounts: StakeReserveAccounts<'_, 'info>,
     args: A,
 ) -> ProgramResult {
-    let ix = stake_reserve_ix(accounts, args)?;
+    let ix = stake_reserve_ix_fixed(accounts, args);
     let account_info: [AccountInfo<'info>; STAKE_RESERVE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke(&ix, &account_info)
 }
@@ -3923,10 +5118,58 @@ pub fn stake_reserve_invoke_signed<'info, A: Into<StakeReserveIxArgs>>(
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = stake_reserve_ix(accounts, args)?;
+    let ix = stake_reserve_ix_fixed(accounts, args);
+    let account_info: [AccountInfo<'info>; STAKE_RESERVE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn stake_reserve_invoke_with_program_id<'info, A: Into<StakeReserveIxArgs>>(
+    program_id: Pubkey,
+    accounts: StakeReserveAccounts<'_, 'info>,
+    args: A,
+) -> ProgramResult {
+    let ix = stake_reserve_ix_fixed_with_program_id(program_id, accounts, args);
+    let account_info: [AccountInfo<'info>; STAKE_RESERVE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn stake_reserve_invoke_signed_with_program_id<'info, A: Into<StakeReserveIxArgs>>(
+    program_id: Pubkey,
+    accounts: StakeReserveAccounts<'_, 'info>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = stake_reserve_ix_fixed_with_program_id(program_id, accounts, args);
     let account_info: [AccountInfo<'info>; STAKE_RESERVE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
+pub fn stake_reserve_invoke_with_slice<A: Into<StakeReserveIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+) -> ProgramResult {
+    let args_full: StakeReserveIxArgs = args.into();
+    let data: StakeReserveIxData = args_full.into();
+    let ix = ix_from_slice::<STAKE_RESERVE_IX_ACCOUNTS_LEN, StakeReserveKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke(&ix, accounts)
+}
+pub fn stake_reserve_invoke_signed_with_slice<A: Into<StakeReserveIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let args_full: StakeReserveIxArgs = args.into();
+    let data: StakeReserveIxData = args_full.into();
+    let ix = ix_from_slice::<STAKE_RESERVE_IX_ACCOUNTS_LEN, StakeReserveKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke_signed(&ix, accounts, seeds)
+}
 pub fn stake_reserve_verify_account_keys(
     accounts: StakeReserveAccounts<'_, '_>,
     keys: StakeReserveKeys,
@@ -4165,6 +5408,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_ACTIVE_IX_ACCOUNTS_LEN]>
     }
 }
 pub const UPDATE_ACTIVE_IX_DISCM: [u8; 8] = [4, 67, 81, 64, 136, 245, 93, 152];
+pub const UPDATE_ACTIVE_IX_DATA_LEN: usize = 16;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateActiveIxArgs {
@@ -4203,26 +5447,58 @@ impl UpdateActiveIxData {
         self.serialize(&mut data)?;
         Ok(data)
     }
+    pub fn to_bytes(&self) -> [u8; UPDATE_ACTIVE_IX_DATA_LEN] {
+        let mut data = [0u8; UPDATE_ACTIVE_IX_DATA_LEN];
+        data[..8].copy_from_slice(&UPDATE_ACTIVE_IX_DISCM);
+        data[8..12].copy_from_slice(&self.0.stake_index.to_le_bytes());
+        data[12..16].copy_from_slice(&self.0.validator_index.to_le_bytes());
+        data
+    }
+}
+pub fn update_active_ix_with_program_id<K: Into<UpdateActiveKeys>, A: Into<UpdateActiveIxArgs>>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    Ok(update_active_ix_fixed_with_program_id(
+        program_id, accounts, args,
+    ))
 }
 pub fn update_active_ix<K: Into<UpdateActiveKeys>, A: Into<UpdateActiveIxArgs>>(
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
+    update_active_ix_with_program_id(crate::ID, accounts, args)
+}
+pub fn update_active_ix_fixed_with_program_id<
+    K: Into<UpdateActiveKeys>,
+    A: Into<UpdateActiveIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> Instruction {
     let keys: UpdateActiveKeys = accounts.into();
     let metas: [AccountMeta; UPDATE_ACTIVE_IX_ACCOUNTS_LEN] = keys.into();
     let args_full: UpdateActiveIxArgs = args.into();
     let data: UpdateActiveIxData = args_full.into();
-    Ok(Instruction {
-        program_id: crate::ID,
+    Instruction {
+        program_id,
         accounts: Vec::from(metas),
-        data: data.try_to_vec()?,
-    })
+        data: Vec::from(data.to_bytes()),
+    }
+}
+pub fn update_active_ix_fixed<K: Into<UpdateActiveKeys>, A: Into<UpdateActiveIxArgs>>(
+    accounts: K,
+    args: A,
+) -> Instruction {
+    update_active_ix_fixed_with_program_id(crate::ID, accounts, args)
 }
 pub fn update_active_invoke<'info, A: Into<UpdateActiveIxArgs>>(
     accounts: UpdateActiveAccounts<'_, 'info>,
     args: A,
 ) -> ProgramResult {
-    let ix = update_active_ix(accounts, args)?;
+    let ix = update_active_ix_fixed(accounts, args);
     let account_info: [AccountInfo<'info>; UPDATE_ACTIVE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke(&ix, &account_info)
 }
@@ -4231,10 +5507,58 @@ pub fn update_active_invoke_signed<'info, A: Into<UpdateActiveIxArgs>>(
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = update_active_ix(accounts, args)?;
+    let ix = update_active_ix_fixed(accounts, args);
+    let account_info: [AccountInfo<'info>; UPDATE_ACTIVE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn update_active_invoke_with_program_id<'info, A: Into<UpdateActiveIxArgs>>(
+    program_id: Pubkey,
+    accounts: UpdateActiveAccounts<'_, 'info>,
+    args: A,
+) -> ProgramResult {
+    let ix = update_active_ix_fixed_with_program_id(program_id, accounts, args);
+    let account_info: [AccountInfo<'info>; UPDATE_ACTIVE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn update_active_invoke_signed_with_program_id<'info, A: Into<UpdateActiveIxArgs>>(
+    program_id: Pubkey,
+    accounts: UpdateActiveAccounts<'_, 'info>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = update_active_ix_fixed_with_program_id(program_id, accounts, args);
     let account_info: [AccountInfo<'info>; UPDATE_ACTIVE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
+pub fn update_active_invoke_with_slice<A: Into<UpdateActiveIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+) -> ProgramResult {
+    let args_full: UpdateActiveIxArgs = args.into();
+    let data: UpdateActiveIxData = args_full.into();
+    let ix = ix_from_slice::<UPDATE_ACTIVE_IX_ACCOUNTS_LEN, UpdateActiveKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke(&ix, accounts)
+}
+pub fn update_active_invoke_signed_with_slice<A: Into<UpdateActiveIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let args_full: UpdateActiveIxArgs = args.into();
+    let data: UpdateActiveIxData = args_full.into();
+    let ix = ix_from_slice::<UPDATE_ACTIVE_IX_ACCOUNTS_LEN, UpdateActiveKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke_signed(&ix, accounts, seeds)
+}
 pub fn update_active_verify_account_keys(
     accounts: UpdateActiveAccounts<'_, '_>,
     keys: UpdateActiveKeys,
@@ -4495,6 +5819,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; UPDATE_DEACTIVATED_IX_ACCOUNTS_L
     }
 }
 pub const UPDATE_DEACTIVATED_IX_DISCM: [u8; 8] = [16, 232, 131, 115, 156, 100, 239, 50];
+pub const UPDATE_DEACTIVATED_IX_DATA_LEN: usize = 12;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateDeactivatedIxArgs {
@@ -4532,26 +5857,63 @@ impl UpdateDeactivatedIxData {
         self.serialize(&mut data)?;
         Ok(data)
     }
+    pub fn to_bytes(&self) -> [u8; UPDATE_DEACTIVATED_IX_DATA_LEN] {
+        let mut data = [0u8; UPDATE_DEACTIVATED_IX_DATA_LEN];
+        data[..8].copy_from_slice(&UPDATE_DEACTIVATED_IX_DISCM);
+        data[8..12].copy_from_slice(&self.0.stake_index.to_le_bytes());
+        data
+    }
+}
+pub fn update_deactivated_ix_with_program_id<
+    K: Into<UpdateDeactivatedKeys>,
+    A: Into<UpdateDeactivatedIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    Ok(update_deactivated_ix_fixed_with_program_id(
+        program_id, accounts, args,
+    ))
 }
 pub fn update_deactivated_ix<K: Into<UpdateDeactivatedKeys>, A: Into<UpdateDeactivatedIxArgs>>(
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
+    update_deactivated_ix_with_program_id(crate::ID, accounts, args)
+}
+pub fn update_deactivated_ix_fixed_with_program_id<
+    K: Into<UpdateDeactivatedKeys>,
+    A: Into<UpdateDeactivatedIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> Instruction {
     let keys: UpdateDeactivatedKeys = accounts.into();
     let metas: [AccountMeta; UPDATE_DEACTIVATED_IX_ACCOUNTS_LEN] = keys.into();
     let args_full: UpdateDeactivatedIxArgs = args.into();
     let data: UpdateDeactivatedIxData = args_full.into();
-    Ok(Instruction {
-        program_id: crate::ID,
+    Instruction {
+        program_id,
         accounts: Vec::from(metas),
-        data: data.try_to_vec()?,
-    })
+        data: Vec::from(data.to_bytes()),
+    }
+}
+pub fn update_deactivated_ix_fixed<
+    K: Into<UpdateDeactivatedKeys>,
+    A: Into<UpdateDeactivatedIxArgs>,
+>(
+    accounts: K,
+    args: A,
+) -> Instruction {
+    update_deactivated_ix_fixed_with_program_id(crate::ID, accounts, args)
 }
 pub fn update_deactivated_invoke<'info, A: Into<UpdateDeactivatedIxArgs>>(
     accounts: UpdateDeactivatedAccounts<'_, 'info>,
     args: A,
 ) -> ProgramResult {
-    let ix = update_deactivated_ix(accounts, args)?;
+    let ix = update_deactivated_ix_fixed(accounts, args);
     let account_info: [AccountInfo<'info>; UPDATE_DEACTIVATED_IX_ACCOUNTS_LEN] = accounts.into();
     invoke(&ix, &account_info)
 }
@@ -4560,10 +5922,58 @@ pub fn update_deactivated_invoke_signed<'info, A: Into<UpdateDeactivatedIxArgs>>
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = update_deactivated_ix(accounts, args)?;
+    let ix = update_deactivated_ix_fixed(accounts, args);
+    let account_info: [AccountInfo<'info>; UPDATE_DEACTIVATED_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn update_deactivated_invoke_with_program_id<'info, A: Into<UpdateDeactivatedIxArgs>>(
+    program_id: Pubkey,
+    accounts: UpdateDeactivatedAccounts<'_, 'info>,
+    args: A,
+) -> ProgramResult {
+    let ix = update_deactivated_ix_fixed_with_program_id(program_id, accounts, args);
+    let account_info: [AccountInfo<'info>; UPDATE_DEACTIVATED_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn update_deactivated_invoke_signed_with_program_id<'info, A: Into<UpdateDeactivatedIxArgs>>(
+    program_id: Pubkey,
+    accounts: UpdateDeactivatedAccounts<'_, 'info>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = update_deactivated_ix_fixed_with_program_id(program_id, accounts, args);
     let account_info: [AccountInfo<'info>; UPDATE_DEACTIVATED_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
+pub fn update_deactivated_invoke_with_slice<A: Into<UpdateDeactivatedIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+) -> ProgramResult {
+    let args_full: UpdateDeactivatedIxArgs = args.into();
+    let data: UpdateDeactivatedIxData = args_full.into();
+    let ix = ix_from_slice::<UPDATE_DEACTIVATED_IX_ACCOUNTS_LEN, UpdateDeactivatedKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke(&ix, accounts)
+}
+pub fn update_deactivated_invoke_signed_with_slice<A: Into<UpdateDeactivatedIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let args_full: UpdateDeactivatedIxArgs = args.into();
+    let data: UpdateDeactivatedIxData = args_full.into();
+    let ix = ix_from_slice::<UPDATE_DEACTIVATED_IX_ACCOUNTS_LEN, UpdateDeactivatedKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke_signed(&ix, accounts, seeds)
+}
 pub fn update_deactivated_verify_account_keys(
     accounts: UpdateDeactivatedAccounts<'_, '_>,
     keys: UpdateDeactivatedKeys,
@@ -4828,6 +6238,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; DEACTIVATE_STAKE_IX_ACCOUNTS_LEN
     }
 }
 pub const DEACTIVATE_STAKE_IX_DISCM: [u8; 8] = [165, 158, 229, 97, 168, 220, 187, 225];
+pub const DEACTIVATE_STAKE_IX_DATA_LEN: usize = 16;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeactivateStakeIxArgs {
@@ -4866,26 +6277,61 @@ impl DeactivateStakeIxData {
         self.serialize(&mut data)?;
         Ok(data)
     }
+    pub fn to_bytes(&self) -> [u8; DEACTIVATE_STAKE_IX_DATA_LEN] {
+        let mut data = [0u8; DEACTIVATE_STAKE_IX_DATA_LEN];
+        data[..8].copy_from_slice(&DEACTIVATE_STAKE_IX_DISCM);
+        data[8..12].copy_from_slice(&self.0.stake_index.to_le_bytes());
+        data[12..16].copy_from_slice(&self.0.validator_index.to_le_bytes());
+        data
+    }
+}
+pub fn deactivate_stake_ix_with_program_id<
+    K: Into<DeactivateStakeKeys>,
+    A: Into<DeactivateStakeIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    Ok(deactivate_stake_ix_fixed_with_program_id(
+        program_id, accounts, args,
+    ))
 }
 pub fn deactivate_stake_ix<K: Into<DeactivateStakeKeys>, A: Into<DeactivateStakeIxArgs>>(
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
+    deactivate_stake_ix_with_program_id(crate::ID, accounts, args)
+}
+pub fn deactivate_stake_ix_fixed_with_program_id<
+    K: Into<DeactivateStakeKeys>,
+    A: Into<DeactivateStakeIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> Instruction {
     let keys: DeactivateStakeKeys = accounts.into();
     let metas: [AccountMeta; DEACTIVATE_STAKE_IX_ACCOUNTS_LEN] = keys.into();
     let args_full: DeactivateStakeIxArgs = args.into();
     let data: DeactivateStakeIxData = args_full.into();
-    Ok(Instruction {
-        program_id: crate::ID,
+    Instruction {
+        program_id,
         accounts: Vec::from(metas),
-        data: data.try_to_vec()?,
-    })
+        data: Vec::from(data.to_bytes()),
+    }
+}
+pub fn deactivate_stake_ix_fixed<K: Into<DeactivateStakeKeys>, A: Into<DeactivateStakeIxArgs>>(
+    accounts: K,
+    args: A,
+) -> Instruction {
+    deactivate_stake_ix_fixed_with_program_id(crate::ID, accounts, args)
 }
 pub fn deactivate_stake_invoke<'info, A: Into<DeactivateStakeIxArgs>>(
     accounts: DeactivateStakeAccounts<'_, 'info>,
     args: A,
 ) -> ProgramResult {
-    let ix = deactivate_stake_ix(accounts, args)?;
+    let ix = deactivate_stake_ix_fixed(accounts, args);
     let account_info: [AccountInfo<'info>; DEACTIVATE_STAKE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke(&ix, &account_info)
 }
@@ -4894,10 +6340,58 @@ pub fn deactivate_stake_invoke_signed<'info, A: Into<DeactivateStakeIxArgs>>(
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = deactivate_stake_ix(accounts, args)?;
+    let ix = deactivate_stake_ix_fixed(accounts, args);
+    let account_info: [AccountInfo<'info>; DEACTIVATE_STAKE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn deactivate_stake_invoke_with_program_id<'info, A: Into<DeactivateStakeIxArgs>>(
+    program_id: Pubkey,
+    accounts: DeactivateStakeAccounts<'_, 'info>,
+    args: A,
+) -> ProgramResult {
+    let ix = deactivate_stake_ix_fixed_with_program_id(program_id, accounts, args);
+    let account_info: [AccountInfo<'info>; DEACTIVATE_STAKE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn deactivate_stake_invoke_signed_with_program_id<'info, A: Into<DeactivateStakeIxArgs>>(
+    program_id: Pubkey,
+    accounts: DeactivateStakeAccounts<'_, 'info>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = deactivate_stake_ix_fixed_with_program_id(program_id, accounts, args);
     let account_info: [AccountInfo<'info>; DEACTIVATE_STAKE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
+pub fn deactivate_stake_invoke_with_slice<A: Into<DeactivateStakeIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+) -> ProgramResult {
+    let args_full: DeactivateStakeIxArgs = args.into();
+    let data: DeactivateStakeIxData = args_full.into();
+    let ix = ix_from_slice::<DEACTIVATE_STAKE_IX_ACCOUNTS_LEN, DeactivateStakeKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke(&ix, accounts)
+}
+pub fn deactivate_stake_invoke_signed_with_slice<A: Into<DeactivateStakeIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let args_full: DeactivateStakeIxArgs = args.into();
+    let data: DeactivateStakeIxData = args_full.into();
+    let ix = ix_from_slice::<DEACTIVATE_STAKE_IX_ACCOUNTS_LEN, DeactivateStakeKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke_signed(&ix, accounts, seeds)
+}
 pub fn deactivate_stake_verify_account_keys(
     accounts: DeactivateStakeAccounts<'_, '_>,
     keys: DeactivateStakeKeys,
@@ -5085,6 +6579,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; EMERGENCY_UNSTAKE_IX_ACCOUNTS_LE
     }
 }
 pub const EMERGENCY_UNSTAKE_IX_DISCM: [u8; 8] = [123, 69, 168, 195, 183, 213, 199, 214];
+pub const EMERGENCY_UNSTAKE_IX_DATA_LEN: usize = 16;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EmergencyUnstakeIxArgs {
@@ -5123,26 +6618,64 @@ impl EmergencyUnstakeIxData {
         self.serialize(&mut data)?;
         Ok(data)
     }
+    pub fn to_bytes(&self) -> [u8; EMERGENCY_UNSTAKE_IX_DATA_LEN] {
+        let mut data = [0u8; EMERGENCY_UNSTAKE_IX_DATA_LEN];
+        data[..8].copy_from_slice(&EMERGENCY_UNSTAKE_IX_DISCM);
+        data[8..12].copy_from_slice(&self.0.stake_index.to_le_bytes());
+        data[12..16].copy_from_slice(&self.0.validator_index.to_le_bytes());
+        data
+    }
+}
+pub fn emergency_unstake_ix_with_program_id<
+    K: Into<EmergencyUnstakeKeys>,
+    A: Into<EmergencyUnstakeIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    Ok(emergency_unstake_ix_fixed_with_program_id(
+        program_id, accounts, args,
+    ))
 }
 pub fn emergency_unstake_ix<K: Into<EmergencyUnstakeKeys>, A: Into<EmergencyUnstakeIxArgs>>(
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
+    emergency_unstake_ix_with_program_id(crate::ID, accounts, args)
+}
+pub fn emergency_unstake_ix_fixed_with_program_id<
+    K: Into<EmergencyUnstakeKeys>,
+    A: Into<EmergencyUnstakeIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> Instruction {
     let keys: EmergencyUnstakeKeys = accounts.into();
     let metas: [AccountMeta; EMERGENCY_UNSTAKE_IX_ACCOUNTS_LEN] = keys.into();
     let args_full: EmergencyUnstakeIxArgs = args.into();
     let data: EmergencyUnstakeIxData = args_full.into();
-    Ok(Instruction {
-        program_id: crate::ID,
+    Instruction {
+        program_id,
         accounts: Vec::from(metas),
-        data: data.try_to_vec()?,
-    })
+        data: Vec::from(data.to_bytes()),
+    }
+}
+pub fn emergency_unstake_ix_fixed<
+    K: Into<EmergencyUnstakeKeys>,
+    A: Into<EmergencyUnstakeIxArgs>,
+>(
+    accounts: K,
+    args: A,
+) -> Instruction {
+    emergency_unstake_ix_fixed_with_program_id(crate::ID, accounts, args)
 }
 pub fn emergency_unstake_invoke<'info, A: Into<EmergencyUnstakeIxArgs>>(
     accounts: EmergencyUnstakeAccounts<'_, 'info>,
     args: A,
 ) -> ProgramResult {
-    let ix = emergency_unstake_ix(accounts, args)?;
+    let ix = emergency_unstake_ix_fixed(accounts, args);
     let account_info: [AccountInfo<'info>; EMERGENCY_UNSTAKE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke(&ix, &account_info)
 }
@@ -5151,31 +6684,79 @@ pub fn emergency_unstake_invoke_signed<'info, A: Into<EmergencyUnstakeIxArgs>>(
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = emergency_unstake_ix(accounts, args)?;
+    let ix = emergency_unstake_ix_fixed(accounts, args);
     let account_info: [AccountInfo<'info>; EMERGENCY_UNSTAKE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
-pub fn emergency_unstake_verify_account_keys(
-    accounts: EmergencyUnstakeAccounts<'_, '_>,
-    keys: EmergencyUnstakeKeys,
-) -> Result<(), (Pubkey, Pubkey)> {
-    for (actual, expected) in [
-        (*accounts.state.key, keys.state),
-        (
-            *accounts.validator_manager_authority.key,
-            keys.validator_manager_authority,
-        ),
-        (*accounts.validator_list.key, keys.validator_list),
-        (*accounts.stake_list.key, keys.stake_list),
-        (*accounts.stake_account.key, keys.stake_account),
-        (
-            *accounts.stake_deposit_authority.key,
-            keys.stake_deposit_authority,
-        ),
-        (*accounts.clock.key, keys.clock),
-        (*accounts.stake_program.key, keys.stake_program),
-    ] {
-        if actual != expected {
+pub fn emergency_unstake_invoke_with_program_id<'info, A: Into<EmergencyUnstakeIxArgs>>(
+    program_id: Pubkey,
+    accounts: EmergencyUnstakeAccounts<'_, 'info>,
+    args: A,
+) -> ProgramResult {
+    let ix = emergency_unstake_ix_fixed_with_program_id(program_id, accounts, args);
+    let account_info: [AccountInfo<'info>; EMERGENCY_UNSTAKE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn emergency_unstake_invoke_signed_with_program_id<'info, A: Into<EmergencyUnstakeIxArgs>>(
+    program_id: Pubkey,
+    accounts: EmergencyUnstakeAccounts<'_, 'info>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = emergency_unstake_ix_fixed_with_program_id(program_id, accounts, args);
+    let account_info: [AccountInfo<'info>; EMERGENCY_UNSTAKE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn emergency_unstake_invoke_with_slice<A: Into<EmergencyUnstakeIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+) -> ProgramResult {
+    let args_full: EmergencyUnstakeIxArgs = args.into();
+    let data: EmergencyUnstakeIxData = args_full.into();
+    let ix = ix_from_slice::<EMERGENCY_UNSTAKE_IX_ACCOUNTS_LEN, EmergencyUnstakeKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke(&ix, accounts)
+}
+pub fn emergency_unstake_invoke_signed_with_slice<A: Into<EmergencyUnstakeIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let args_full: EmergencyUnstakeIxArgs = args.into();
+    let data: EmergencyUnstakeIxData = args_full.into();
+    let ix = ix_from_slice::<EMERGENCY_UNSTAKE_IX_ACCOUNTS_LEN, EmergencyUnstakeKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke_signed(&ix, accounts, seeds)
+}
+pub fn emergency_unstake_verify_account_keys(
+    accounts: EmergencyUnstakeAccounts<'_, '_>,
+    keys: EmergencyUnstakeKeys,
+) -> Result<(), (Pubkey, Pubkey)> {
+    for (actual, expected) in [
+        (*accounts.state.key, keys.state),
+        (
+            *accounts.validator_manager_authority.key,
+            keys.validator_manager_authority,
+        ),
+        (*accounts.validator_list.key, keys.validator_list),
+        (*accounts.stake_list.key, keys.stake_list),
+        (*accounts.stake_account.key, keys.stake_account),
+        (
+            *accounts.stake_deposit_authority.key,
+            keys.stake_deposit_authority,
+        ),
+        (*accounts.clock.key, keys.clock),
+        (*accounts.stake_program.key, keys.stake_program),
+    ] {
+        if actual != expected {
             return Err((actual, expected));
         }
     }
@@ -5397,6 +6978,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; PARTIAL_UNSTAKE_IX_ACCOUNTS_LEN]
     }
 }
 pub const PARTIAL_UNSTAKE_IX_DISCM: [u8; 8] = [55, 241, 205, 221, 45, 114, 205, 163];
+pub const PARTIAL_UNSTAKE_IX_DATA_LEN: usize = 24;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PartialUnstakeIxArgs {
@@ -5436,26 +7018,62 @@ impl PartialUnstakeIxData {
         self.serialize(&mut data)?;
         Ok(data)
     }
+    pub fn to_bytes(&self) -> [u8; PARTIAL_UNSTAKE_IX_DATA_LEN] {
+        let mut data = [0u8; PARTIAL_UNSTAKE_IX_DATA_LEN];
+        data[..8].copy_from_slice(&PARTIAL_UNSTAKE_IX_DISCM);
+        data[8..12].copy_from_slice(&self.0.stake_index.to_le_bytes());
+        data[12..16].copy_from_slice(&self.0.validator_index.to_le_bytes());
+        data[16..24].copy_from_slice(&self.0.desired_unstake_amount.to_le_bytes());
+        data
+    }
+}
+pub fn partial_unstake_ix_with_program_id<
+    K: Into<PartialUnstakeKeys>,
+    A: Into<PartialUnstakeIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    Ok(partial_unstake_ix_fixed_with_program_id(
+        program_id, accounts, args,
+    ))
 }
 pub fn partial_unstake_ix<K: Into<PartialUnstakeKeys>, A: Into<PartialUnstakeIxArgs>>(
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
+    partial_unstake_ix_with_program_id(crate::ID, accounts, args)
+}
+pub fn partial_unstake_ix_fixed_with_program_id<
+    K: Into<PartialUnstakeKeys>,
+    A: Into<PartialUnstakeIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> Instruction {
     let keys: PartialUnstakeKeys = accounts.into();
     let metas: [AccountMeta; PARTIAL_UNSTAKE_IX_ACCOUNTS_LEN] = keys.into();
     let args_full: PartialUnstakeIxArgs = args.into();
     let data: PartialUnstakeIxData = args_full.into();
-    Ok(Instruction {
-        program_id: crate::ID,
+    Instruction {
+        program_id,
         accounts: Vec::from(metas),
-        data: data.try_to_vec()?,
-    })
+        data: Vec::from(data.to_bytes()),
+    }
+}
+pub fn partial_unstake_ix_fixed<K: Into<PartialUnstakeKeys>, A: Into<PartialUnstakeIxArgs>>(
+    accounts: K,
+    args: A,
+) -> Instruction {
+    partial_unstake_ix_fixed_with_program_id(crate::ID, accounts, args)
 }
 pub fn partial_unstake_invoke<'info, A: Into<PartialUnstakeIxArgs>>(
     accounts: PartialUnstakeAccounts<'_, 'info>,
     args: A,
 ) -> ProgramResult {
-    let ix = partial_unstake_ix(accounts, args)?;
+    let ix = partial_unstake_ix_fixed(accounts, args);
     let account_info: [AccountInfo<'info>; PARTIAL_UNSTAKE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke(&ix, &account_info)
 }
@@ -5464,10 +7082,58 @@ pub fn partial_unstake_invoke_signed<'info, A: Into<PartialUnstakeIxArgs>>(
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = partial_unstake_ix(accounts, args)?;
+    let ix = partial_unstake_ix_fixed(accounts, args);
+    let account_info: [AccountInfo<'info>; PARTIAL_UNSTAKE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn partial_unstake_invoke_with_program_id<'info, A: Into<PartialUnstakeIxArgs>>(
+    program_id: Pubkey,
+    accounts: PartialUnstakeAccounts<'_, 'info>,
+    args: A,
+) -> ProgramResult {
+    let ix = partial_unstake_ix_fixed_with_program_id(program_id, accounts, args);
+    let account_info: [AccountInfo<'info>; PARTIAL_UNSTAKE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn partial_unstake_invoke_signed_with_program_id<'info, A: Into<PartialUnstakeIxArgs>>(
+    program_id: Pubkey,
+    accounts: PartialUnstakeAccounts<'_, 'info>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = partial_unstake_ix_fixed_with_program_id(program_id, accounts, args);
     let account_info: [AccountInfo<'info>; PARTIAL_UNSTAKE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
+pub fn partial_unstake_invoke_with_slice<A: Into<PartialUnstakeIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+) -> ProgramResult {
+    let args_full: PartialUnstakeIxArgs = args.into();
+    let data: PartialUnstakeIxData = args_full.into();
+    let ix = ix_from_slice::<PARTIAL_UNSTAKE_IX_ACCOUNTS_LEN, PartialUnstakeKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke(&ix, accounts)
+}
+pub fn partial_unstake_invoke_signed_with_slice<A: Into<PartialUnstakeIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let args_full: PartialUnstakeIxArgs = args.into();
+    let data: PartialUnstakeIxData = args_full.into();
+    let ix = ix_from_slice::<PARTIAL_UNSTAKE_IX_ACCOUNTS_LEN, PartialUnstakeKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke_signed(&ix, accounts, seeds)
+}
 pub fn partial_unstake_verify_account_keys(
     accounts: PartialUnstakeAccounts<'_, '_>,
     keys: PartialUnstakeKeys,
@@ -5692,6 +7358,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; MERGE_STAKES_IX_ACCOUNTS_LEN]>
     }
 }
 pub const MERGE_STAKES_IX_DISCM: [u8; 8] = [216, 36, 141, 225, 243, 78, 125, 237];
+pub const MERGE_STAKES_IX_DATA_LEN: usize = 20;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MergeStakesIxArgs {
@@ -5731,26 +7398,59 @@ impl MergeStakesIxData {
         self.serialize(&mut data)?;
         Ok(data)
     }
+    pub fn to_bytes(&self) -> [u8; MERGE_STAKES_IX_DATA_LEN] {
+        let mut data = [0u8; MERGE_STAKES_IX_DATA_LEN];
+        data[..8].copy_from_slice(&MERGE_STAKES_IX_DISCM);
+        data[8..12].copy_from_slice(&self.0.destination_stake_index.to_le_bytes());
+        data[12..16].copy_from_slice(&self.0.source_stake_index.to_le_bytes());
+        data[16..20].copy_from_slice(&self.0.validator_index.to_le_bytes());
+        data
+    }
+}
+pub fn merge_stakes_ix_with_program_id<K: Into<MergeStakesKeys>, A: Into<MergeStakesIxArgs>>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    Ok(merge_stakes_ix_fixed_with_program_id(
+        program_id, accounts, args,
+    ))
 }
 pub fn merge_stakes_ix<K: Into<MergeStakesKeys>, A: Into<MergeStakesIxArgs>>(
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
+    merge_stakes_ix_with_program_id(crate::ID, accounts, args)
+}
+pub fn merge_stakes_ix_fixed_with_program_id<
+    K: Into<MergeStakesKeys>,
+    A: Into<MergeStakesIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> Instruction {
     let keys: MergeStakesKeys = accounts.into();
     let metas: [AccountMeta; MERGE_STAKES_IX_ACCOUNTS_LEN] = keys.into();
     let args_full: MergeStakesIxArgs = args.into();
     let data: MergeStakesIxData = args_full.into();
-    Ok(Instruction {
-        program_id: crate::ID,
+    Instruction {
+        program_id,
         accounts: Vec::from(metas),
-        data: data.try_to_vec()?,
-    })
+        data: Vec::from(data.to_bytes()),
+    }
+}
+pub fn merge_stakes_ix_fixed<K: Into<MergeStakesKeys>, A: Into<MergeStakesIxArgs>>(
+    accounts: K,
+    args: A,
+) -> Instruction {
+    merge_stakes_ix_fixed_with_program_id(crate::ID, accounts, args)
 }
 pub fn merge_stakes_invoke<'info, A: Into<MergeStakesIxArgs>>(
     accounts: MergeStakesAccounts<'_, 'info>,
     args: A,
 ) -> ProgramResult {
-    let ix = merge_stakes_ix(accounts, args)?;
+    let ix = merge_stakes_ix_fixed(accounts, args);
     let account_info: [AccountInfo<'info>; MERGE_STAKES_IX_ACCOUNTS_LEN] = accounts.into();
     invoke(&ix, &account_info)
 }
@@ -5759,10 +7459,58 @@ pub fn merge_stakes_invoke_signed<'info, A: Into<MergeStakesIxArgs>>(
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = merge_stakes_ix(accounts, args)?;
+    let ix = merge_stakes_ix_fixed(accounts, args);
+    let account_info: [AccountInfo<'info>; MERGE_STAKES_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn merge_stakes_invoke_with_program_id<'info, A: Into<MergeStakesIxArgs>>(
+    program_id: Pubkey,
+    accounts: MergeStakesAccounts<'_, 'info>,
+    args: A,
+) -> ProgramResult {
+    let ix = merge_stakes_ix_fixed_with_program_id(program_id, accounts, args);
+    let account_info: [AccountInfo<'info>; MERGE_STAKES_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn merge_stakes_invoke_signed_with_program_id<'info, A: Into<MergeStakesIxArgs>>(
+    program_id: Pubkey,
+    accounts: MergeStakesAccounts<'_, 'info>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = merge_stakes_ix_fixed_with_program_id(program_id, accounts, args);
     let account_info: [AccountInfo<'info>; MERGE_STAKES_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
+pub fn merge_stakes_invoke_with_slice<A: Into<MergeStakesIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+) -> ProgramResult {
+    let args_full: MergeStakesIxArgs = args.into();
+    let data: MergeStakesIxData = args_full.into();
+    let ix = ix_from_slice::<MERGE_STAKES_IX_ACCOUNTS_LEN, MergeStakesKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke(&ix, accounts)
+}
+pub fn merge_stakes_invoke_signed_with_slice<A: Into<MergeStakesIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let args_full: MergeStakesIxArgs = args.into();
+    let data: MergeStakesIxData = args_full.into();
+    let ix = ix_from_slice::<MERGE_STAKES_IX_ACCOUNTS_LEN, MergeStakesKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke_signed(&ix, accounts, seeds)
+}
 pub fn merge_stakes_verify_account_keys(
     accounts: MergeStakesAccounts<'_, '_>,
     keys: MergeStakesKeys,
@@ -6019,6 +7767,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; REDELEGATE_IX_ACCOUNTS_LEN]>
     }
 }
 pub const REDELEGATE_IX_DISCM: [u8; 8] = [212, 82, 51, 160, 228, 80, 116, 35];
+pub const REDELEGATE_IX_DATA_LEN: usize = 20;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RedelegateIxArgs {
@@ -6058,26 +7807,56 @@ impl RedelegateIxData {
         self.serialize(&mut data)?;
         Ok(data)
     }
+    pub fn to_bytes(&self) -> [u8; REDELEGATE_IX_DATA_LEN] {
+        let mut data = [0u8; REDELEGATE_IX_DATA_LEN];
+        data[..8].copy_from_slice(&REDELEGATE_IX_DISCM);
+        data[8..12].copy_from_slice(&self.0.stake_index.to_le_bytes());
+        data[12..16].copy_from_slice(&self.0.source_validator_index.to_le_bytes());
+        data[16..20].copy_from_slice(&self.0.dest_validator_index.to_le_bytes());
+        data
+    }
+}
+pub fn redelegate_ix_with_program_id<K: Into<RedelegateKeys>, A: Into<RedelegateIxArgs>>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    Ok(redelegate_ix_fixed_with_program_id(
+        program_id, accounts, args,
+    ))
 }
 pub fn redelegate_ix<K: Into<RedelegateKeys>, A: Into<RedelegateIxArgs>>(
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
+    redelegate_ix_with_program_id(crate::ID, accounts, args)
+}
+pub fn redelegate_ix_fixed_with_program_id<K: Into<RedelegateKeys>, A: Into<RedelegateIxArgs>>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> Instruction {
     let keys: RedelegateKeys = accounts.into();
     let metas: [AccountMeta; REDELEGATE_IX_ACCOUNTS_LEN] = keys.into();
     let args_full: RedelegateIxArgs = args.into();
     let data: RedelegateIxData = args_full.into();
-    Ok(Instruction {
-        program_id: crate::ID,
+    Instruction {
+        program_id,
         accounts: Vec::from(metas),
-        data: data.try_to_vec()?,
-    })
+        data: Vec::from(data.to_bytes()),
+    }
+}
+pub fn redelegate_ix_fixed<K: Into<RedelegateKeys>, A: Into<RedelegateIxArgs>>(
+    accounts: K,
+    args: A,
+) -> Instruction {
+    redelegate_ix_fixed_with_program_id(crate::ID, accounts, args)
 }
 pub fn redelegate_invoke<'info, A: Into<RedelegateIxArgs>>(
     accounts: RedelegateAccounts<'_, 'info>,
     args: A,
 ) -> ProgramResult {
-    let ix = redelegate_ix(accounts, args)?;
+    let ix = redelegate_ix_fixed(accounts, args);
     let account_info: [AccountInfo<'info>; REDELEGATE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke(&ix, &account_info)
 }
@@ -6086,10 +7865,58 @@ pub fn redelegate_invoke_signed<'info, A: Into<RedelegateIxArgs>>(
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = redelegate_ix(accounts, args)?;
+    let ix = redelegate_ix_fixed(accounts, args);
     let account_info: [AccountInfo<'info>; REDELEGATE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
+pub fn redelegate_invoke_with_program_id<'info, A: Into<RedelegateIxArgs>>(
+    program_id: Pubkey,
+    accounts: RedelegateAccounts<'_, 'info>,
+    args: A,
+) -> ProgramResult {
+    let ix = redelegate_ix_fixed_with_program_id(program_id, accounts, args);
+    let account_info: [AccountInfo<'info>; REDELEGATE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn redelegate_invoke_signed_with_program_id<'info, A: Into<RedelegateIxArgs>>(
+    program_id: Pubkey,
+    accounts: RedelegateAccounts<'_, 'info>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = redelegate_ix_fixed_with_program_id(program_id, accounts, args);
+    let account_info: [AccountInfo<'info>; REDELEGATE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn redelegate_invoke_with_slice<A: Into<RedelegateIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+) -> ProgramResult {
+    let args_full: RedelegateIxArgs = args.into();
+    let data: RedelegateIxData = args_full.into();
+    let ix = ix_from_slice::<REDELEGATE_IX_ACCOUNTS_LEN, RedelegateKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke(&ix, accounts)
+}
+pub fn redelegate_invoke_signed_with_slice<A: Into<RedelegateIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let args_full: RedelegateIxArgs = args.into();
+    let data: RedelegateIxData = args_full.into();
+    let ix = ix_from_slice::<REDELEGATE_IX_ACCOUNTS_LEN, RedelegateKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke_signed(&ix, accounts, seeds)
+}
 pub fn redelegate_verify_account_keys(
     accounts: RedelegateAccounts<'_, '_>,
     keys: RedelegateKeys,
@@ -6215,6 +8042,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; PAUSE_IX_ACCOUNTS_LEN]>
     }
 }
 pub const PAUSE_IX_DISCM: [u8; 8] = [211, 22, 221, 251, 74, 121, 193, 47];
+pub const PAUSE_IX_DATA_LEN: usize = 8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct PauseIxData;
 impl PauseIxData {
@@ -6241,18 +8069,36 @@ impl PauseIxData {
         self.serialize(&mut data)?;
         Ok(data)
     }
+    pub fn to_bytes(&self) -> [u8; PAUSE_IX_DATA_LEN] {
+        PAUSE_IX_DISCM
+    }
+}
+pub fn pause_ix_with_program_id<K: Into<PauseKeys>>(
+    program_id: Pubkey,
+    accounts: K,
+) -> std::io::Result<Instruction> {
+    Ok(pause_ix_fixed_with_program_id(program_id, accounts))
 }
 pub fn pause_ix<K: Into<PauseKeys>>(accounts: K) -> std::io::Result<Instruction> {
+    pause_ix_with_program_id(crate::ID, accounts)
+}
+pub fn pause_ix_fixed_with_program_id<K: Into<PauseKeys>>(
+    program_id: Pubkey,
+    accounts: K,
+) -> Instruction {
     let keys: PauseKeys = accounts.into();
     let metas: [AccountMeta; PAUSE_IX_ACCOUNTS_LEN] = keys.into();
-    Ok(Instruction {
-        program_id: crate::ID,
+    Instruction {
+        program_id,
         accounts: Vec::from(metas),
-        data: PauseIxData.try_to_vec()?,
-    })
+        data: Vec::from(PauseIxData.to_bytes()),
+    }
+}
+pub fn pause_ix_fixed<K: Into<PauseKeys>>(accounts: K) -> Instruction {
+    pause_ix_fixed_with_program_id(crate::ID, accounts)
 }
 pub fn pause_invoke<'info>(accounts: PauseAccounts<'_, 'info>) -> ProgramResult {
-    let ix = pause_ix(accounts)?;
+    let ix = pause_ix_fixed(accounts);
     let account_info: [AccountInfo<'info>; PAUSE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke(&ix, &account_info)
 }
@@ -6260,10 +8106,47 @@ pub fn pause_invoke_signed<'info>(
     accounts: PauseAccounts<'_, 'info>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = pause_ix(accounts)?;
+    let ix = pause_ix_fixed(accounts);
     let account_info: [AccountInfo<'info>; PAUSE_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
+pub fn pause_invoke_with_program_id<'info>(
+    program_id: Pubkey,
+    accounts: PauseAccounts<'_, 'info>,
+) -> ProgramResult {
+    let ix = pause_ix_fixed_with_program_id(program_id, accounts);
+    let account_info: [AccountInfo<'info>; PAUSE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn pause_invoke_signed_with_program_id<'info>(
+    program_id: Pubkey,
+    accounts: PauseAccounts<'_, 'info>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = pause_ix_fixed_with_program_id(program_id, accounts);
+    let account_info: [AccountInfo<'info>; PAUSE_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn pause_invoke_with_slice(program_id: Pubkey, accounts: &[AccountInfo<'_>]) -> ProgramResult {
+    let ix = ix_from_slice::<PAUSE_IX_ACCOUNTS_LEN, PauseKeys>(
+        program_id,
+        accounts,
+        Vec::from(PauseIxData.to_bytes()),
+    )?;
+    invoke(&ix, accounts)
+}
+pub fn pause_invoke_signed_with_slice(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = ix_from_slice::<PAUSE_IX_ACCOUNTS_LEN, PauseKeys>(
+        program_id,
+        accounts,
+        Vec::from(PauseIxData.to_bytes()),
+    )?;
+    invoke_signed(&ix, accounts, seeds)
+}
 pub fn pause_verify_account_keys(
     accounts: PauseAccounts<'_, '_>,
     keys: PauseKeys,
@@ -6352,6 +8235,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; RESUME_IX_ACCOUNTS_LEN]>
     }
 }
 pub const RESUME_IX_DISCM: [u8; 8] = [1, 166, 51, 170, 127, 32, 141, 206];
+pub const RESUME_IX_DATA_LEN: usize = 8;
 #[derive(Clone, Debug, PartialEq)]
 pub struct ResumeIxData;
 impl ResumeIxData {
@@ -6378,18 +8262,36 @@ impl ResumeIxData {
         self.serialize(&mut data)?;
         Ok(data)
     }
+    pub fn to_bytes(&self) -> [u8; RESUME_IX_DATA_LEN] {
+        RESUME_IX_DISCM
+    }
+}
+pub fn resume_ix_with_program_id<K: Into<ResumeKeys>>(
+    program_id: Pubkey,
+    accounts: K,
+) -> std::io::Result<Instruction> {
+    Ok(resume_ix_fixed_with_program_id(program_id, accounts))
 }
 pub fn resume_ix<K: Into<ResumeKeys>>(accounts: K) -> std::io::Result<Instruction> {
+    resume_ix_with_program_id(crate::ID, accounts)
+}
+pub fn resume_ix_fixed_with_program_id<K: Into<ResumeKeys>>(
+    program_id: Pubkey,
+    accounts: K,
+) -> Instruction {
     let keys: ResumeKeys = accounts.into();
     let metas: [AccountMeta; RESUME_IX_ACCOUNTS_LEN] = keys.into();
-    Ok(Instruction {
-        program_id: crate::ID,
+    Instruction {
+        program_id,
         accounts: Vec::from(metas),
-        data: ResumeIxData.try_to_vec()?,
-    })
+        data: Vec::from(ResumeIxData.to_bytes()),
+    }
+}
+pub fn resume_ix_fixed<K: Into<ResumeKeys>>(accounts: K) -> Instruction {
+    resume_ix_fixed_with_program_id(crate::ID, accounts)
 }
 pub fn resume_invoke<'info>(accounts: ResumeAccounts<'_, 'info>) -> ProgramResult {
-    let ix = resume_ix(accounts)?;
+    let ix = resume_ix_fixed(accounts);
     let account_info: [AccountInfo<'info>; RESUME_IX_ACCOUNTS_LEN] = accounts.into();
     invoke(&ix, &account_info)
 }
@@ -6397,10 +8299,47 @@ pub fn resume_invoke_signed<'info>(
     accounts: ResumeAccounts<'_, 'info>,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = resume_ix(accounts)?;
+    let ix = resume_ix_fixed(accounts);
+    let account_info: [AccountInfo<'info>; RESUME_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn resume_invoke_with_program_id<'info>(
+    program_id: Pubkey,
+    accounts: ResumeAccounts<'_, 'info>,
+) -> ProgramResult {
+    let ix = resume_ix_fixed_with_program_id(program_id, accounts);
+    let account_info: [AccountInfo<'info>; RESUME_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn resume_invoke_signed_with_program_id<'info>(
+    program_id: Pubkey,
+    accounts: ResumeAccounts<'_, 'info>,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = resume_ix_fixed_with_program_id(program_id, accounts);
     let account_info: [AccountInfo<'info>; RESUME_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
+pub fn resume_invoke_with_slice(program_id: Pubkey, accounts: &[AccountInfo<'_>]) -> ProgramResult {
+    let ix = ix_from_slice::<RESUME_IX_ACCOUNTS_LEN, ResumeKeys>(
+        program_id,
+        accounts,
+        Vec::from(ResumeIxData.to_bytes()),
+    )?;
+    invoke(&ix, accounts)
+}
+pub fn resume_invoke_signed_with_slice(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = ix_from_slice::<RESUME_IX_ACCOUNTS_LEN, ResumeKeys>(
+        program_id,
+        accounts,
+        Vec::from(ResumeIxData.to_bytes()),
+    )?;
+    invoke_signed(&ix, accounts, seeds)
+}
 pub fn resume_verify_account_keys(
     accounts: ResumeAccounts<'_, '_>,
     keys: ResumeKeys,
@@ -6648,6 +8587,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; WITHDRAW_STAKE_ACCOUNT_IX_ACCOUN
     }
 }
 pub const WITHDRAW_STAKE_ACCOUNT_IX_DISCM: [u8; 8] = [211, 85, 184, 65, 183, 177, 233, 217];
+pub const WITHDRAW_STAKE_ACCOUNT_IX_DATA_LEN: usize = 56;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WithdrawStakeAccountIxArgs {
@@ -6688,6 +8628,27 @@ impl WithdrawStakeAccountIxData {
         self.serialize(&mut data)?;
         Ok(data)
     }
+    pub fn to_bytes(&self) -> [u8; WITHDRAW_STAKE_ACCOUNT_IX_DATA_LEN] {
+        let mut data = [0u8; WITHDRAW_STAKE_ACCOUNT_IX_DATA_LEN];
+        data[..8].copy_from_slice(&WITHDRAW_STAKE_ACCOUNT_IX_DISCM);
+        data[8..12].copy_from_slice(&self.0.stake_index.to_le_bytes());
+        data[12..16].copy_from_slice(&self.0.validator_index.to_le_bytes());
+        data[16..24].copy_from_slice(&self.0.msol_amount.to_le_bytes());
+        data[24..56].copy_from_slice(self.0.beneficiary.as_ref());
+        data
+    }
+}
+pub fn withdraw_stake_account_ix_with_program_id<
+    K: Into<WithdrawStakeAccountKeys>,
+    A: Into<WithdrawStakeAccountIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    Ok(withdraw_stake_account_ix_fixed_with_program_id(
+        program_id, accounts, args,
+    ))
 }
 pub fn withdraw_stake_account_ix<
     K: Into<WithdrawStakeAccountKeys>,
@@ -6696,21 +8657,40 @@ pub fn withdraw_stake_account_ix<
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
+    withdraw_stake_account_ix_with_program_id(crate::ID, accounts, args)
+}
+pub fn withdraw_stake_account_ix_fixed_with_program_id<
+    K: Into<WithdrawStakeAccountKeys>,
+    A: Into<WithdrawStakeAccountIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> Instruction {
     let keys: WithdrawStakeAccountKeys = accounts.into();
     let metas: [AccountMeta; WITHDRAW_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] = keys.into();
     let args_full: WithdrawStakeAccountIxArgs = args.into();
     let data: WithdrawStakeAccountIxData = args_full.into();
-    Ok(Instruction {
-        program_id: crate::ID,
+    Instruction {
+        program_id,
         accounts: Vec::from(metas),
-        data: data.try_to_vec()?,
-    })
+        data: Vec::from(data.to_bytes()),
+    }
+}
+pub fn withdraw_stake_account_ix_fixed<
+    K: Into<WithdrawStakeAccountKeys>,
+    A: Into<WithdrawStakeAccountIxArgs>,
+>(
+    accounts: K,
+    args: A,
+) -> Instruction {
+    withdraw_stake_account_ix_fixed_with_program_id(crate::ID, accounts, args)
 }
 pub fn withdraw_stake_account_invoke<'info, A: Into<WithdrawStakeAccountIxArgs>>(
     accounts: WithdrawStakeAccountAccounts<'_, 'info>,
     args: A,
 ) -> ProgramResult {
-    let ix = withdraw_stake_account_ix(accounts, args)?;
+    let ix = withdraw_stake_account_ix_fixed(accounts, args);
     let account_info: [AccountInfo<'info>; WITHDRAW_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] =
         accounts.into();
     invoke(&ix, &account_info)
@@ -6720,11 +8700,64 @@ pub fn withdraw_stake_account_invoke_signed<'info, A: Into<WithdrawStakeAccountI
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = withdraw_stake_account_ix(accounts, args)?;
+    let ix = withdraw_stake_account_ix_fixed(accounts, args);
+    let account_info: [AccountInfo<'info>; WITHDRAW_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] =
+        accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn withdraw_stake_account_invoke_with_program_id<'info, A: Into<WithdrawStakeAccountIxArgs>>(
+    program_id: Pubkey,
+    accounts: WithdrawStakeAccountAccounts<'_, 'info>,
+    args: A,
+) -> ProgramResult {
+    let ix = withdraw_stake_account_ix_fixed_with_program_id(program_id, accounts, args);
+    let account_info: [AccountInfo<'info>; WITHDRAW_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] =
+        accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn withdraw_stake_account_invoke_signed_with_program_id<
+    'info,
+    A: Into<WithdrawStakeAccountIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: WithdrawStakeAccountAccounts<'_, 'info>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = withdraw_stake_account_ix_fixed_with_program_id(program_id, accounts, args);
     let account_info: [AccountInfo<'info>; WITHDRAW_STAKE_ACCOUNT_IX_ACCOUNTS_LEN] =
         accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
+pub fn withdraw_stake_account_invoke_with_slice<A: Into<WithdrawStakeAccountIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+) -> ProgramResult {
+    let args_full: WithdrawStakeAccountIxArgs = args.into();
+    let data: WithdrawStakeAccountIxData = args_full.into();
+    let ix = ix_from_slice::<WITHDRAW_STAKE_ACCOUNT_IX_ACCOUNTS_LEN, WithdrawStakeAccountKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke(&ix, accounts)
+}
+pub fn withdraw_stake_account_invoke_signed_with_slice<A: Into<WithdrawStakeAccountIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let args_full: WithdrawStakeAccountIxArgs = args.into();
+    let data: WithdrawStakeAccountIxData = args_full.into();
+    let ix = ix_from_slice::<WITHDRAW_STAKE_ACCOUNT_IX_ACCOUNTS_LEN, WithdrawStakeAccountKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke_signed(&ix, accounts, seeds)
+}
 pub fn withdraw_stake_account_verify_account_keys(
     accounts: WithdrawStakeAccountAccounts<'_, '_>,
     keys: WithdrawStakeAccountKeys,
@@ -6892,6 +8925,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; REALLOC_VALIDATOR_LIST_IX_ACCOUN
     }
 }
 pub const REALLOC_VALIDATOR_LIST_IX_DISCM: [u8; 8] = [215, 59, 218, 133, 93, 138, 60, 123];
+pub const REALLOC_VALIDATOR_LIST_IX_DATA_LEN: usize = 12;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReallocValidatorListIxArgs {
@@ -6929,6 +8963,24 @@ impl ReallocValidatorListIxData {
         self.serialize(&mut data)?;
         Ok(data)
     }
+    pub fn to_bytes(&self) -> [u8; REALLOC_VALIDATOR_LIST_IX_DATA_LEN] {
+        let mut data = [0u8; REALLOC_VALIDATOR_LIST_IX_DATA_LEN];
+        data[..8].copy_from_slice(&REALLOC_VALIDATOR_LIST_IX_DISCM);
+        data[8..12].copy_from_slice(&self.0.capacity.to_le_bytes());
+        data
+    }
+}
+pub fn realloc_validator_list_ix_with_program_id<
+    K: Into<ReallocValidatorListKeys>,
+    A: Into<ReallocValidatorListIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    Ok(realloc_validator_list_ix_fixed_with_program_id(
+        program_id, accounts, args,
+    ))
 }
 pub fn realloc_validator_list_ix<
     K: Into<ReallocValidatorListKeys>,
@@ -6937,21 +8989,40 @@ pub fn realloc_validator_list_ix<
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
+    realloc_validator_list_ix_with_program_id(crate::ID, accounts, args)
+}
+pub fn realloc_validator_list_ix_fixed_with_program_id<
+    K: Into<ReallocValidatorListKeys>,
+    A: Into<ReallocValidatorListIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> Instruction {
     let keys: ReallocValidatorListKeys = accounts.into();
     let metas: [AccountMeta; REALLOC_VALIDATOR_LIST_IX_ACCOUNTS_LEN] = keys.into();
     let args_full: ReallocValidatorListIxArgs = args.into();
     let data: ReallocValidatorListIxData = args_full.into();
-    Ok(Instruction {
-        program_id: crate::ID,
+    Instruction {
+        program_id,
         accounts: Vec::from(metas),
-        data: data.try_to_vec()?,
-    })
+        data: Vec::from(data.to_bytes()),
+    }
+}
+pub fn realloc_validator_list_ix_fixed<
+    K: Into<ReallocValidatorListKeys>,
+    A: Into<ReallocValidatorListIxArgs>,
+>(
+    accounts: K,
+    args: A,
+) -> Instruction {
+    realloc_validator_list_ix_fixed_with_program_id(crate::ID, accounts, args)
 }
 pub fn realloc_validator_list_invoke<'info, A: Into<ReallocValidatorListIxArgs>>(
     accounts: ReallocValidatorListAccounts<'_, 'info>,
     args: A,
 ) -> ProgramResult {
-    let ix = realloc_validator_list_ix(accounts, args)?;
+    let ix = realloc_validator_list_ix_fixed(accounts, args);
     let account_info: [AccountInfo<'info>; REALLOC_VALIDATOR_LIST_IX_ACCOUNTS_LEN] =
         accounts.into();
     invoke(&ix, &account_info)
@@ -6961,11 +9032,64 @@ pub fn realloc_validator_list_invoke_signed<'info, A: Into<ReallocValidatorListI
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = realloc_validator_list_ix(accounts, args)?;
+    let ix = realloc_validator_list_ix_fixed(accounts, args);
     let account_info: [AccountInfo<'info>; REALLOC_VALIDATOR_LIST_IX_ACCOUNTS_LEN] =
         accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
+pub fn realloc_validator_list_invoke_with_program_id<'info, A: Into<ReallocValidatorListIxArgs>>(
+    program_id: Pubkey,
+    accounts: ReallocValidatorListAccounts<'_, 'info>,
+    args: A,
+) -> ProgramResult {
+    let ix = realloc_validator_list_ix_fixed_with_program_id(program_id, accounts, args);
+    let account_info: [AccountInfo<'info>; REALLOC_VALIDATOR_LIST_IX_ACCOUNTS_LEN] =
+        accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn realloc_validator_list_invoke_signed_with_program_id<
+    'info,
+    A: Into<ReallocValidatorListIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: ReallocValidatorListAccounts<'_, 'info>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = realloc_validator_list_ix_fixed_with_program_id(program_id, accounts, args);
+    let account_info: [AccountInfo<'info>; REALLOC_VALIDATOR_LIST_IX_ACCOUNTS_LEN] =
+        accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn realloc_validator_list_invoke_with_slice<A: Into<ReallocValidatorListIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+) -> ProgramResult {
+    let args_full: ReallocValidatorListIxArgs = args.into();
+    let data: ReallocValidatorListIxData = args_full.into();
+    let ix = ix_from_slice::<REALLOC_VALIDATOR_LIST_IX_ACCOUNTS_LEN, ReallocValidatorListKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke(&ix, accounts)
+}
+pub fn realloc_validator_list_invoke_signed_with_slice<A: Into<ReallocValidatorListIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let args_full: ReallocValidatorListIxArgs = args.into();
+    let data: ReallocValidatorListIxData = args_full.into();
+    let ix = ix_from_slice::<REALLOC_VALIDATOR_LIST_IX_ACCOUNTS_LEN, ReallocValidatorListKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke_signed(&ix, accounts, seeds)
+}
 pub fn realloc_validator_list_verify_account_keys(
     accounts: ReallocValidatorListAccounts<'_, '_>,
     keys: ReallocValidatorListKeys,
@@ -7095,6 +9219,7 @@ impl<'me, 'info> From<&'me [AccountInfo<'info>; REALLOC_STAKE_LIST_IX_ACCOUNTS_L
     }
 }
 pub const REALLOC_STAKE_LIST_IX_DISCM: [u8; 8] = [12, 36, 124, 27, 128, 96, 85, 199];
+pub const REALLOC_STAKE_LIST_IX_DATA_LEN: usize = 12;
 #[derive(BorshDeserialize, BorshSerialize, Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReallocStakeListIxArgs {
@@ -7132,26 +9257,63 @@ impl ReallocStakeListIxData {
         self.serialize(&mut data)?;
         Ok(data)
     }
+    pub fn to_bytes(&self) -> [u8; REALLOC_STAKE_LIST_IX_DATA_LEN] {
+        let mut data = [0u8; REALLOC_STAKE_LIST_IX_DATA_LEN];
+        data[..8].copy_from_slice(&REALLOC_STAKE_LIST_IX_DISCM);
+        data[8..12].copy_from_slice(&self.0.capacity.to_le_bytes());
+        data
+    }
+}
+pub fn realloc_stake_list_ix_with_program_id<
+    K: Into<ReallocStakeListKeys>,
+    A: Into<ReallocStakeListIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> std::io::Result<Instruction> {
+    Ok(realloc_stake_list_ix_fixed_with_program_id(
+        program_id, accounts, args,
+    ))
 }
 pub fn realloc_stake_list_ix<K: Into<ReallocStakeListKeys>, A: Into<ReallocStakeListIxArgs>>(
     accounts: K,
     args: A,
 ) -> std::io::Result<Instruction> {
+    realloc_stake_list_ix_with_program_id(crate::ID, accounts, args)
+}
+pub fn realloc_stake_list_ix_fixed_with_program_id<
+    K: Into<ReallocStakeListKeys>,
+    A: Into<ReallocStakeListIxArgs>,
+>(
+    program_id: Pubkey,
+    accounts: K,
+    args: A,
+) -> Instruction {
     let keys: ReallocStakeListKeys = accounts.into();
     let metas: [AccountMeta; REALLOC_STAKE_LIST_IX_ACCOUNTS_LEN] = keys.into();
     let args_full: ReallocStakeListIxArgs = args.into();
     let data: ReallocStakeListIxData = args_full.into();
-    Ok(Instruction {
-        program_id: crate::ID,
+    Instruction {
+        program_id,
         accounts: Vec::from(metas),
-        data: data.try_to_vec()?,
-    })
+        data: Vec::from(data.to_bytes()),
+    }
+}
+pub fn realloc_stake_list_ix_fixed<
+    K: Into<ReallocStakeListKeys>,
+    A: Into<ReallocStakeListIxArgs>,
+>(
+    accounts: K,
+    args: A,
+) -> Instruction {
+    realloc_stake_list_ix_fixed_with_program_id(crate::ID, accounts, args)
 }
 pub fn realloc_stake_list_invoke<'info, A: Into<ReallocStakeListIxArgs>>(
     accounts: ReallocStakeListAccounts<'_, 'info>,
     args: A,
 ) -> ProgramResult {
-    let ix = realloc_stake_list_ix(accounts, args)?;
+    let ix = realloc_stake_list_ix_fixed(accounts, args);
     let account_info: [AccountInfo<'info>; REALLOC_STAKE_LIST_IX_ACCOUNTS_LEN] = accounts.into();
     invoke(&ix, &account_info)
 }
@@ -7160,10 +9322,58 @@ pub fn realloc_stake_list_invoke_signed<'info, A: Into<ReallocStakeListIxArgs>>(
     args: A,
     seeds: &[&[&[u8]]],
 ) -> ProgramResult {
-    let ix = realloc_stake_list_ix(accounts, args)?;
+    let ix = realloc_stake_list_ix_fixed(accounts, args);
     let account_info: [AccountInfo<'info>; REALLOC_STAKE_LIST_IX_ACCOUNTS_LEN] = accounts.into();
     invoke_signed(&ix, &account_info, seeds)
 }
+pub fn realloc_stake_list_invoke_with_program_id<'info, A: Into<ReallocStakeListIxArgs>>(
+    program_id: Pubkey,
+    accounts: ReallocStakeListAccounts<'_, 'info>,
+    args: A,
+) -> ProgramResult {
+    let ix = realloc_stake_list_ix_fixed_with_program_id(program_id, accounts, args);
+    let account_info: [AccountInfo<'info>; REALLOC_STAKE_LIST_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke(&ix, &account_info)
+}
+pub fn realloc_stake_list_invoke_signed_with_program_id<'info, A: Into<ReallocStakeListIxArgs>>(
+    program_id: Pubkey,
+    accounts: ReallocStakeListAccounts<'_, 'info>,
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = realloc_stake_list_ix_fixed_with_program_id(program_id, accounts, args);
+    let account_info: [AccountInfo<'info>; REALLOC_STAKE_LIST_IX_ACCOUNTS_LEN] = accounts.into();
+    invoke_signed(&ix, &account_info, seeds)
+}
+pub fn realloc_stake_list_invoke_with_slice<A: Into<ReallocStakeListIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+) -> ProgramResult {
+    let args_full: ReallocStakeListIxArgs = args.into();
+    let data: ReallocStakeListIxData = args_full.into();
+    let ix = ix_from_slice::<REALLOC_STAKE_LIST_IX_ACCOUNTS_LEN, ReallocStakeListKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke(&ix, accounts)
+}
+pub fn realloc_stake_list_invoke_signed_with_slice<A: Into<ReallocStakeListIxArgs>>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    args: A,
+    seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let args_full: ReallocStakeListIxArgs = args.into();
+    let data: ReallocStakeListIxData = args_full.into();
+    let ix = ix_from_slice::<REALLOC_STAKE_LIST_IX_ACCOUNTS_LEN, ReallocStakeListKeys>(
+        program_id,
+        accounts,
+        Vec::from(data.to_bytes()),
+    )?;
+    invoke_signed(&ix, accounts, seeds)
+}
 pub fn realloc_stake_list_verify_account_keys(
     accounts: ReallocStakeListAccounts<'_, '_>,
     keys: ReallocStakeListKeys,
@@ -7196,3 +9406,528 @@ pub fn realloc_stake_list_verify_account_privileges<'me, 'info>(
     }
     Ok(())
 }
+#[derive(Clone, Debug, PartialEq)]
+pub enum MarinadeFinanceProgramIxWithKeys {
+    Initialize(InitializeKeys, InitializeIxArgs),
+    ChangeAuthority(ChangeAuthorityKeys, ChangeAuthorityIxArgs),
+    AddValidator(AddValidatorKeys, AddValidatorIxArgs),
+    RemoveValidator(RemoveValidatorKeys, RemoveValidatorIxArgs),
+    SetValidatorScore(SetValidatorScoreKeys, SetValidatorScoreIxArgs),
+    ConfigValidatorSystem(ConfigValidatorSystemKeys, ConfigValidatorSystemIxArgs),
+    Deposit(DepositKeys, DepositIxArgs),
+    DepositStakeAccount(DepositStakeAccountKeys, DepositStakeAccountIxArgs),
+    LiquidUnstake(LiquidUnstakeKeys, LiquidUnstakeIxArgs),
+    AddLiquidity(AddLiquidityKeys, AddLiquidityIxArgs),
+    RemoveLiquidity(RemoveLiquidityKeys, RemoveLiquidityIxArgs),
+    ConfigLp(ConfigLpKeys, ConfigLpIxArgs),
+    ConfigMarinade(ConfigMarinadeKeys, ConfigMarinadeIxArgs),
+    OrderUnstake(OrderUnstakeKeys, OrderUnstakeIxArgs),
+    Claim(ClaimKeys),
+    StakeReserve(StakeReserveKeys, StakeReserveIxArgs),
+    UpdateActive(UpdateActiveKeys, UpdateActiveIxArgs),
+    UpdateDeactivated(UpdateDeactivatedKeys, UpdateDeactivatedIxArgs),
+    DeactivateStake(DeactivateStakeKeys, DeactivateStakeIxArgs),
+    EmergencyUnstake(EmergencyUnstakeKeys, EmergencyUnstakeIxArgs),
+    PartialUnstake(PartialUnstakeKeys, PartialUnstakeIxArgs),
+    MergeStakes(MergeStakesKeys, MergeStakesIxArgs),
+    Redelegate(RedelegateKeys, RedelegateIxArgs),
+    Pause(PauseKeys),
+    Resume(ResumeKeys),
+    WithdrawStakeAccount(WithdrawStakeAccountKeys, WithdrawStakeAccountIxArgs),
+    ReallocValidatorList(ReallocValidatorListKeys, ReallocValidatorListIxArgs),
+    ReallocStakeList(ReallocStakeListKeys, ReallocStakeListIxArgs),
+}
+impl MarinadeFinanceProgramIxWithKeys {
+    pub fn deserialize(buf: &[u8], accounts: &[Pubkey]) -> std::io::Result<Self> {
+        Ok(match MarinadeFinanceProgramIx::deserialize(buf)? {
+            MarinadeFinanceProgramIx::Initialize(args) => Self::Initialize(
+                keys_from_accounts::<INITIALIZE_IX_ACCOUNTS_LEN, _>(accounts)?,
+                args,
+            ),
+            MarinadeFinanceProgramIx::ChangeAuthority(args) => Self::ChangeAuthority(
+                keys_from_accounts::<CHANGE_AUTHORITY_IX_ACCOUNTS_LEN, _>(accounts)?,
+                args,
+            ),
+            MarinadeFinanceProgramIx::AddValidator(args) => Self::AddValidator(
+                keys_from_accounts::<ADD_VALIDATOR_IX_ACCOUNTS_LEN, _>(accounts)?,
+                args,
+            ),
+            MarinadeFinanceProgramIx::RemoveValidator(args) => Self::RemoveValidator(
+                keys_from_accounts::<REMOVE_VALIDATOR_IX_ACCOUNTS_LEN, _>(accounts)?,
+                args,
+            ),
+            MarinadeFinanceProgramIx::SetValidatorScore(args) => Self::SetValidatorScore(
+                keys_from_accounts::<SET_VALIDATOR_SCORE_IX_ACCOUNTS_LEN, _>(accounts)?,
+                args,
+            ),
+            MarinadeFinanceProgramIx::ConfigValidatorSystem(args) => Self::ConfigValidatorSystem(
+                keys_from_accounts::<CONFIG_VALIDATOR_SYSTEM_IX_ACCOUNTS_LEN, _>(accounts)?,
+                args,
+            ),
+            MarinadeFinanceProgramIx::Deposit(args) => Self::Deposit(
+                keys_from_accounts::<DEPOSIT_IX_ACCOUNTS_LEN, _>(accounts)?,
+                args,
+            ),
+            MarinadeFinanceProgramIx::DepositStakeAccount(args) => Self::DepositStakeAccount(
+                keys_from_accounts::<DEPOSIT_STAKE_ACCOUNT_IX_ACCOUNTS_LEN, _>(accounts)?,
+                args,
+            ),
+            MarinadeFinanceProgramIx::LiquidUnstake(args) => Self::LiquidUnstake(
+                keys_from_accounts::<LIQUID_UNSTAKE_IX_ACCOUNTS_LEN, _>(accounts)?,
+                args,
+            ),
+            MarinadeFinanceProgramIx::AddLiquidity(args) => Self::AddLiquidity(
+                keys_from_accounts::<ADD_LIQUIDITY_IX_ACCOUNTS_LEN, _>(accounts)?,
+                args,
+            ),
+            MarinadeFinanceProgramIx::RemoveLiquidity(args) => Self::RemoveLiquidity(
+                keys_from_accounts::<REMOVE_LIQUIDITY_IX_ACCOUNTS_LEN, _>(accounts)?,
+                args,
+            ),
+            MarinadeFinanceProgramIx::ConfigLp(args) => Self::ConfigLp(
+                keys_from_accounts::<CONFIG_LP_IX_ACCOUNTS_LEN, _>(accounts)?,
+                args,
+            ),
+            MarinadeFinanceProgramIx::ConfigMarinade(args) => Self::ConfigMarinade(
+                keys_from_accounts::<CONFIG_MARINADE_IX_ACCOUNTS_LEN, _>(accounts)?,
+                args,
+            ),
+            MarinadeFinanceProgramIx::OrderUnstake(args) => Self::OrderUnstake(
+                keys_from_accounts::<ORDER_UNSTAKE_IX_ACCOUNTS_LEN, _>(accounts)?,
+                args,
+            ),
+            MarinadeFinanceProgramIx::Claim => {
+                Self::Claim(keys_from_accounts::<CLAIM_IX_ACCOUNTS_LEN, _>(accounts)?)
+            }
+            MarinadeFinanceProgramIx::StakeReserve(args) => Self::StakeReserve(
+                keys_from_accounts::<STAKE_RESERVE_IX_ACCOUNTS_LEN, _>(accounts)?,
+                args,
+            ),
+            MarinadeFinanceProgramIx::UpdateActive(args) => Self::UpdateActive(
+                keys_from_accounts::<UPDATE_ACTIVE_IX_ACCOUNTS_LEN, _>(accounts)?,
+                args,
+            ),
+            MarinadeFinanceProgramIx::UpdateDeactivated(args) => Self::UpdateDeactivated(
+                keys_from_accounts::<UPDATE_DEACTIVATED_IX_ACCOUNTS_LEN, _>(accounts)?,
+                args,
+            ),
+            MarinadeFinanceProgramIx::DeactivateStake(args) => Self::DeactivateStake(
+                keys_from_accounts::<DEACTIVATE_STAKE_IX_ACCOUNTS_LEN, _>(accounts)?,
+                args,
+            ),
+            MarinadeFinanceProgramIx::EmergencyUnstake(args) => Self::EmergencyUnstake(
+                keys_from_accounts::<EMERGENCY_UNSTAKE_IX_ACCOUNTS_LEN, _>(accounts)?,
+                args,
+            ),
+            MarinadeFinanceProgramIx::PartialUnstake(args) => Self::PartialUnstake(
+                keys_from_accounts::<PARTIAL_UNSTAKE_IX_ACCOUNTS_LEN, _>(accounts)?,
+                args,
+            ),
+            MarinadeFinanceProgramIx::MergeStakes(args) => Self::MergeStakes(
+                keys_from_accounts::<MERGE_STAKES_IX_ACCOUNTS_LEN, _>(accounts)?,
+                args,
+            ),
+            MarinadeFinanceProgramIx::Redelegate(args) => Self::Redelegate(
+                keys_from_accounts::<REDELEGATE_IX_ACCOUNTS_LEN, _>(accounts)?,
+                args,
+            ),
+            MarinadeFinanceProgramIx::Pause => {
+                Self::Pause(keys_from_accounts::<PAUSE_IX_ACCOUNTS_LEN, _>(accounts)?)
+            }
+            MarinadeFinanceProgramIx::Resume => {
+                Self::Resume(keys_from_accounts::<RESUME_IX_ACCOUNTS_LEN, _>(accounts)?)
+            }
+            MarinadeFinanceProgramIx::WithdrawStakeAccount(args) => Self::WithdrawStakeAccount(
+                keys_from_accounts::<WITHDRAW_STAKE_ACCOUNT_IX_ACCOUNTS_LEN, _>(accounts)?,
+                args,
+            ),
+            MarinadeFinanceProgramIx::ReallocValidatorList(args) => Self::ReallocValidatorList(
+                keys_from_accounts::<REALLOC_VALIDATOR_LIST_IX_ACCOUNTS_LEN, _>(accounts)?,
+                args,
+            ),
+            MarinadeFinanceProgramIx::ReallocStakeList(args) => Self::ReallocStakeList(
+                keys_from_accounts::<REALLOC_STAKE_LIST_IX_ACCOUNTS_LEN, _>(accounts)?,
+                args,
+            ),
+        })
+    }
+    pub fn from_instruction(ix: &Instruction) -> std::io::Result<Self> {
+        if ix.program_id != crate::ID {
+            return Err(std::io::Error::other(format!(
+                "program id does not match. Expected: {}. Received: {}",
+                crate::ID,
+                ix.program_id
+            )));
+        }
+        let accounts: Vec<Pubkey> = ix.accounts.iter().map(|meta| meta.pubkey).collect();
+        Self::deserialize(&ix.data, &accounts)
+    }
+    pub fn from_compiled_instruction(
+        ix: &CompiledInstruction,
+        account_keys: &[Pubkey],
+    ) -> std::io::Result<Self> {
+        let key_at = |index: u8| {
+            account_keys.get(index as usize).copied().ok_or_else(|| {
+                std::io::Error::other(format!("account index {} out of bounds", index))
+            })
+        };
+        let program_id = key_at(ix.program_id_index)?;
+        if program_id != crate::ID {
+            return Err(std::io::Error::other(format!(
+                "program id does not match. Expected: {}. Received: {}",
+                crate::ID,
+                program_id
+            )));
+        }
+        let accounts = ix
+            .accounts
+            .iter()
+            .map(|index| key_at(*index))
+            .collect::<std::io::Result<Vec<Pubkey>>>()?;
+        Self::deserialize(&ix.data, &accounts)
+    }
+    pub fn ix(&self) -> MarinadeFinanceProgramIx {
+        match self {
+            Self::Initialize(_, args) => MarinadeFinanceProgramIx::Initialize(args.clone()),
+            Self::ChangeAuthority(_, args) => {
+                MarinadeFinanceProgramIx::ChangeAuthority(args.clone())
+            }
+            Self::AddValidator(_, args) => MarinadeFinanceProgramIx::AddValidator(args.clone()),
+            Self::RemoveValidator(_, args) => {
+                MarinadeFinanceProgramIx::RemoveValidator(args.clone())
+            }
+            Self::SetValidatorScore(_, args) => {
+                MarinadeFinanceProgramIx::SetValidatorScore(args.clone())
+            }
+            Self::ConfigValidatorSystem(_, args) => {
+                MarinadeFinanceProgramIx::ConfigValidatorSystem(args.clone())
+            }
+            Self::Deposit(_, args) => MarinadeFinanceProgramIx::Deposit(args.clone()),
+            Self::DepositStakeAccount(_, args) => {
+                MarinadeFinanceProgramIx::DepositStakeAccount(args.clone())
+            }
+            Self::LiquidUnstake(_, args) => MarinadeFinanceProgramIx::LiquidUnstake(args.clone()),
+            Self::AddLiquidity(_, args) => MarinadeFinanceProgramIx::AddLiquidity(args.clone()),
+            Self::RemoveLiquidity(_, args) => {
+                MarinadeFinanceProgramIx::RemoveLiquidity(args.clone())
+            }
+            Self::ConfigLp(_, args) => MarinadeFinanceProgramIx::ConfigLp(args.clone()),
+            Self::ConfigMarinade(_, args) => MarinadeFinanceProgramIx::ConfigMarinade(args.clone()),
+            Self::OrderUnstake(_, args) => MarinadeFinanceProgramIx::OrderUnstake(args.clone()),
+            Self::Claim(_) => MarinadeFinanceProgramIx::Claim,
+            Self::StakeReserve(_, args) => MarinadeFinanceProgramIx::StakeReserve(args.clone()),
+            Self::UpdateActive(_, args) => MarinadeFinanceProgramIx::UpdateActive(args.clone()),
+            Self::UpdateDeactivated(_, args) => {
+                MarinadeFinanceProgramIx::UpdateDeactivated(args.clone())
+            }
+            Self::DeactivateStake(_, args) => {
+                MarinadeFinanceProgramIx::DeactivateStake(args.clone())
+            }
+            Self::EmergencyUnstake(_, args) => {
+                MarinadeFinanceProgramIx::EmergencyUnstake(args.clone())
+            }
+            Self::PartialUnstake(_, args) => MarinadeFinanceProgramIx::PartialUnstake(args.clone()),
+            Self::MergeStakes(_, args) => MarinadeFinanceProgramIx::MergeStakes(args.clone()),
+            Self::Redelegate(_, args) => MarinadeFinanceProgramIx::Redelegate(args.clone()),
+            Self::Pause(_) => MarinadeFinanceProgramIx::Pause,
+            Self::Resume(_) => MarinadeFinanceProgramIx::Resume,
+            Self::WithdrawStakeAccount(_, args) => {
+                MarinadeFinanceProgramIx::WithdrawStakeAccount(args.clone())
+            }
+            Self::ReallocValidatorList(_, args) => {
+                MarinadeFinanceProgramIx::ReallocValidatorList(args.clone())
+            }
+            Self::ReallocStakeList(_, args) => {
+                MarinadeFinanceProgramIx::ReallocStakeList(args.clone())
+            }
+        }
+    }
+    pub fn to_instruction(&self) -> std::io::Result<Instruction> {
+        match self {
+            Self::Initialize(keys, args) => initialize_ix(*keys, args.clone()),
+            Self::ChangeAuthority(keys, args) => change_authority_ix(*keys, args.clone()),
+            Self::AddValidator(keys, args) => add_validator_ix(*keys, args.clone()),
+            Self::RemoveValidator(keys, args) => remove_validator_ix(*keys, args.clone()),
+            Self::SetValidatorScore(keys, args) => set_validator_score_ix(*keys, args.clone()),
+            Self::ConfigValidatorSystem(keys, args) => {
+                config_validator_system_ix(*keys, args.clone())
+            }
+            Self::Deposit(keys, args) => deposit_ix(*keys, args.clone()),
+            Self::DepositStakeAccount(keys, args) => deposit_stake_account_ix(*keys, args.clone()),
+            Self::LiquidUnstake(keys, args) => liquid_unstake_ix(*keys, args.clone()),
+            Self::AddLiquidity(keys, args) => add_liquidity_ix(*keys, args.clone()),
+            Self::RemoveLiquidity(keys, args) => remove_liquidity_ix(*keys, args.clone()),
+            Self::ConfigLp(keys, args) => config_lp_ix(*keys, args.clone()),
+            Self::ConfigMarinade(keys, args) => config_marinade_ix(*keys, args.clone()),
+            Self::OrderUnstake(keys, args) => order_unstake_ix(*keys, args.clone()),
+            Self::Claim(keys) => claim_ix(*keys),
+            Self::StakeReserve(keys, args) => stake_reserve_ix(*keys, args.clone()),
+            Self::UpdateActive(keys, args) => update_active_ix(*keys, args.clone()),
+            Self::UpdateDeactivated(keys, args) => update_deactivated_ix(*keys, args.clone()),
+            Self::DeactivateStake(keys, args) => deactivate_stake_ix(*keys, args.clone()),
+            Self::EmergencyUnstake(keys, args) => emergency_unstake_ix(*keys, args.clone()),
+            Self::PartialUnstake(keys, args) => partial_unstake_ix(*keys, args.clone()),
+            Self::MergeStakes(keys, args) => merge_stakes_ix(*keys, args.clone()),
+            Self::Redelegate(keys, args) => redelegate_ix(*keys, args.clone()),
+            Self::Pause(keys) => pause_ix(*keys),
+            Self::Resume(keys) => resume_ix(*keys),
+            Self::WithdrawStakeAccount(keys, args) => {
+                withdraw_stake_account_ix(*keys, args.clone())
+            }
+            Self::ReallocValidatorList(keys, args) => {
+                realloc_validator_list_ix(*keys, args.clone())
+            }
+            Self::ReallocStakeList(keys, args) => realloc_stake_list_ix(*keys, args.clone()),
+        }
+    }
+}
+impl TryFrom<&Instruction> for MarinadeFinanceProgramIxWithKeys {
+    type Error = std::io::Error;
+    fn try_from(ix: &Instruction) -> std::io::Result<Self> {
+        Self::from_instruction(ix)
+    }
+}
+fn keys_from_accounts<const N: usize, K: From<[Pubkey; N]>>(
+    accounts: &[Pubkey],
+) -> std::io::Result<K> {
+    let pubkeys: [Pubkey; N] = accounts
+        .get(..N)
+        .and_then(|pubkeys| pubkeys.try_into().ok())
+        .ok_or_else(|| {
+            std::io::Error::other(format!(
+                "not enough accounts. Expected: {}. Received: {}",
+                N,
+                accounts.len()
+            ))
+        })?;
+    Ok(pubkeys.into())
+}
+fn ix_from_slice<const N: usize, K>(
+    program_id: Pubkey,
+    accounts: &[AccountInfo<'_>],
+    data: Vec<u8>,
+) -> Result<Instruction, ProgramError>
+where
+    K: From<[Pubkey; N]> + Into<[AccountMeta; N]>,
+{
+    if accounts.len() < N {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let (ix_accounts, remaining_accounts) = accounts.split_at(N);
+    let keys = K::from(std::array::from_fn(|i| *ix_accounts[i].key));
+    let metas: [AccountMeta; N] = keys.into();
+    let mut account_metas = Vec::with_capacity(accounts.len());
+    account_metas.extend(metas);
+    account_metas.extend(remaining_accounts.iter().map(|account| AccountMeta {
+        pubkey: *account.key,
+        is_signer: account.is_signer,
+        is_writable: account.is_writable,
+    }));
+    Ok(Instruction {
+        program_id,
+        accounts: account_metas,
+        data,
+    })
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::patterned;
+    macro_rules! fixed_encoding_tests {
+        ($($name:ident: $data:expr,)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    let data = $data;
+                    assert_eq!(data.to_bytes().to_vec(), data.try_to_vec().unwrap());
+                }
+            )*
+        };
+    }
+    fixed_encoding_tests! {
+        initialize_fixed_encoding: InitializeIxData(patterned::<InitializeIxArgs>()),
+        add_validator_fixed_encoding: AddValidatorIxData(patterned::<AddValidatorIxArgs>()),
+        remove_validator_fixed_encoding: RemoveValidatorIxData(patterned::<RemoveValidatorIxArgs>()),
+        set_validator_score_fixed_encoding: SetValidatorScoreIxData(patterned::<SetValidatorScoreIxArgs>()),
+        config_validator_system_fixed_encoding: ConfigValidatorSystemIxData(patterned::<ConfigValidatorSystemIxArgs>()),
+        deposit_fixed_encoding: DepositIxData(patterned::<DepositIxArgs>()),
+        deposit_stake_account_fixed_encoding: DepositStakeAccountIxData(patterned::<DepositStakeAccountIxArgs>()),
+        liquid_unstake_fixed_encoding: LiquidUnstakeIxData(patterned::<LiquidUnstakeIxArgs>()),
+        add_liquidity_fixed_encoding: AddLiquidityIxData(patterned::<AddLiquidityIxArgs>()),
+        remove_liquidity_fixed_encoding: RemoveLiquidityIxData(patterned::<RemoveLiquidityIxArgs>()),
+        order_unstake_fixed_encoding: OrderUnstakeIxData(patterned::<OrderUnstakeIxArgs>()),
+        stake_reserve_fixed_encoding: StakeReserveIxData(patterned::<StakeReserveIxArgs>()),
+        update_active_fixed_encoding: UpdateActiveIxData(patterned::<UpdateActiveIxArgs>()),
+        update_deactivated_fixed_encoding: UpdateDeactivatedIxData(patterned::<UpdateDeactivatedIxArgs>()),
+        deactivate_stake_fixed_encoding: DeactivateStakeIxData(patterned::<DeactivateStakeIxArgs>()),
+        emergency_unstake_fixed_encoding: EmergencyUnstakeIxData(patterned::<EmergencyUnstakeIxArgs>()),
+        partial_unstake_fixed_encoding: PartialUnstakeIxData(patterned::<PartialUnstakeIxArgs>()),
+        merge_stakes_fixed_encoding: MergeStakesIxData(patterned::<MergeStakesIxArgs>()),
+        redelegate_fixed_encoding: RedelegateIxData(patterned::<RedelegateIxArgs>()),
+        withdraw_stake_account_fixed_encoding: WithdrawStakeAccountIxData(patterned::<WithdrawStakeAccountIxArgs>()),
+        realloc_validator_list_fixed_encoding: ReallocValidatorListIxData(patterned::<ReallocValidatorListIxArgs>()),
+        realloc_stake_list_fixed_encoding: ReallocStakeListIxData(patterned::<ReallocStakeListIxArgs>()),
+        claim_fixed_encoding: ClaimIxData,
+        pause_fixed_encoding: PauseIxData,
+        resume_fixed_encoding: ResumeIxData,
+    }
+    #[test]
+    fn ix_from_slice_appends_remaining_accounts() {
+        let keys: Vec<Pubkey> = (0..CLAIM_IX_ACCOUNTS_LEN + 2)
+            .map(|_| Pubkey::new_unique())
+            .collect();
+        let mut lamports = vec![0u64; keys.len()];
+        let mut data = vec![[0u8; 0]; keys.len()];
+        let accounts: Vec<AccountInfo> = keys
+            .iter()
+            .zip(lamports.iter_mut())
+            .zip(data.iter_mut())
+            .enumerate()
+            .map(|(i, ((key, lamports), data))| {
+                AccountInfo::new(
+                    key,
+                    i == keys.len() - 1,
+                    i % 2 == 0,
+                    lamports,
+                    data,
+                    key,
+                    false,
+                    0,
+                )
+            })
+            .collect();
+        let program_id = Pubkey::new_unique();
+        let ix = ix_from_slice::<CLAIM_IX_ACCOUNTS_LEN, ClaimKeys>(
+            program_id,
+            &accounts,
+            Vec::from(ClaimIxData.to_bytes()),
+        )
+        .unwrap();
+        let expected = claim_ix_with_program_id(
+            program_id,
+            ClaimKeys::from(
+                <[Pubkey; CLAIM_IX_ACCOUNTS_LEN]>::try_from(&keys[..CLAIM_IX_ACCOUNTS_LEN])
+                    .unwrap(),
+            ),
+        )
+        .unwrap();
+        assert_eq!(ix.program_id, program_id);
+        assert_eq!(ix.data, expected.data);
+        assert_eq!(ix.accounts[..CLAIM_IX_ACCOUNTS_LEN], expected.accounts[..]);
+        let remaining: Vec<AccountMeta> = accounts[CLAIM_IX_ACCOUNTS_LEN..]
+            .iter()
+            .map(|account| AccountMeta {
+                pubkey: *account.key,
+                is_signer: account.is_signer,
+                is_writable: account.is_writable,
+            })
+            .collect();
+        assert_eq!(ix.accounts[CLAIM_IX_ACCOUNTS_LEN..], remaining[..]);
+        assert!(ix.accounts.last().unwrap().is_signer);
+    }
+    #[test]
+    fn ix_from_slice_rejects_short_slice() {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = [];
+        let account =
+            AccountInfo::new(&key, false, false, &mut lamports, &mut data, &key, false, 0);
+        let accounts = vec![account; CLAIM_IX_ACCOUNTS_LEN - 1];
+        assert_eq!(
+            claim_invoke_with_slice(crate::ID, &accounts),
+            Err(ProgramError::NotEnoughAccountKeys)
+        );
+    }
+    fn unique_keys<const N: usize>() -> [Pubkey; N] {
+        std::array::from_fn(|_| Pubkey::new_unique())
+    }
+    #[test]
+    fn with_keys_rejects_too_few_accounts() {
+        let data = DepositIxData(DepositIxArgs { lamports: 1 }).to_bytes();
+        let accounts = unique_keys::<DEPOSIT_IX_ACCOUNTS_LEN>();
+        let err = MarinadeFinanceProgramIxWithKeys::deserialize(
+            &data,
+            &accounts[..DEPOSIT_IX_ACCOUNTS_LEN - 1],
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "not enough accounts. Expected: {}. Received: {}",
+                DEPOSIT_IX_ACCOUNTS_LEN,
+                DEPOSIT_IX_ACCOUNTS_LEN - 1
+            )
+        );
+        let mut with_remaining = accounts.to_vec();
+        with_remaining.push(Pubkey::new_unique());
+        assert_eq!(
+            MarinadeFinanceProgramIxWithKeys::deserialize(&data, &with_remaining).unwrap(),
+            MarinadeFinanceProgramIxWithKeys::Deposit(
+                DepositKeys::from(accounts),
+                DepositIxArgs { lamports: 1 }
+            )
+        );
+        assert!(
+            MarinadeFinanceProgramIxWithKeys::deserialize(&ClaimIxData.to_bytes(), &[]).is_err()
+        );
+    }
+    #[test]
+    fn with_keys_rejects_other_program_id() {
+        let mut ix = claim_ix(ClaimKeys::from(unique_keys::<CLAIM_IX_ACCOUNTS_LEN>())).unwrap();
+        ix.program_id = Pubkey::new_unique();
+        let err = MarinadeFinanceProgramIxWithKeys::from_instruction(&ix).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "program id does not match. Expected: {}. Received: {}",
+                crate::ID,
+                ix.program_id
+            )
+        );
+        assert!(MarinadeFinanceProgramIxWithKeys::try_from(&ix).is_err());
+        let mut account_keys: Vec<Pubkey> = ix.accounts.iter().map(|meta| meta.pubkey).collect();
+        account_keys.push(ix.program_id);
+        let compiled = CompiledInstruction {
+            program_id_index: (account_keys.len() - 1) as u8,
+            accounts: (0..CLAIM_IX_ACCOUNTS_LEN as u8).collect(),
+            data: ix.data.clone(),
+        };
+        assert!(MarinadeFinanceProgramIxWithKeys::from_compiled_instruction(
+            &compiled,
+            &account_keys
+        )
+        .is_err());
+        *account_keys.last_mut().unwrap() = crate::ID;
+        assert_eq!(
+            MarinadeFinanceProgramIxWithKeys::from_compiled_instruction(&compiled, &account_keys)
+                .unwrap(),
+            MarinadeFinanceProgramIxWithKeys::Claim(ClaimKeys::from(
+                <[Pubkey; CLAIM_IX_ACCOUNTS_LEN]>::try_from(&account_keys[..CLAIM_IX_ACCOUNTS_LEN])
+                    .unwrap()
+            ))
+        );
+    }
+    #[test]
+    fn with_keys_to_instruction_round_trip() {
+        let ixs = [
+            MarinadeFinanceProgramIxWithKeys::Deposit(
+                DepositKeys::from(unique_keys::<DEPOSIT_IX_ACCOUNTS_LEN>()),
+                DepositIxArgs {
+                    lamports: 1_000_000_000,
+                },
+            ),
+            MarinadeFinanceProgramIxWithKeys::Claim(ClaimKeys::from(unique_keys::<
+                CLAIM_IX_ACCOUNTS_LEN,
+            >())),
+        ];
+        for ix in ixs {
+            let instruction = ix.to_instruction().unwrap();
+            assert_eq!(instruction.program_id, crate::ID);
+            assert_eq!(instruction.data, ix.ix().try_to_vec().unwrap());
+            assert_eq!(
+                MarinadeFinanceProgramIxWithKeys::from_instruction(&instruction).unwrap(),
+                ix
+            );
+        }
+    }
+}
@@ -9,3 +9,26 @@ pub mod errors;
 pub use errors::*;
 pub mod events;
 pub use events::*;
+pub mod pda;
+pub use pda::*;
+pub mod resolve;
+pub use resolve::*;
+pub mod list;
+pub use list::*;
+pub mod calc;
+pub use calc::*;
+pub mod quote;
+pub use quote::*;
+pub mod fee;
+pub use fee::*;
+pub mod ticket;
+pub use ticket::*;
+pub mod logs;
+pub use logs::*;
+#[cfg(feature = "serde")]
+pub mod serde_utils;
+pub mod transaction;
+pub use transaction::*;
+pub mod state;
+#[cfg(test)]
+mod test_utils;
@@ -0,0 +1,238 @@
+use crate::*;
+use borsh::BorshDeserialize;
+use std::marker::PhantomData;
+pub const LIST_HEADER_LEN: usize = 8;
+pub const STAKE_LIST_DISCM: [u8; 8] = *b"staker__";
+pub const VALIDATOR_LIST_DISCM: [u8; 8] = *b"validatr";
+pub const STAKE_RECORD_LEN: usize = 32 + 8 + 8 + 1;
+pub const VALIDATOR_RECORD_LEN: usize = 32 + 8 + 4 + 8 + 1;
+#[derive(Debug)]
+pub struct ListView<'a, T> {
+    items: &'a [u8],
+    item_size: usize,
+    count: u32,
+    invalid_list: MarinadeFinanceError,
+    record: PhantomData<T>,
+}
+impl<T> Clone for ListView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T> Copy for ListView<'_, T> {}
+pub type StakeListView<'a> = ListView<'a, StakeRecord>;
+pub type ValidatorListView<'a> = ListView<'a, ValidatorRecord>;
+impl<'a, T: BorshDeserialize> ListView<'a, T> {
+    fn new(
+        buf: &'a [u8],
+        list: &List,
+        discm: [u8; 8],
+        record_len: usize,
+        invalid_list: MarinadeFinanceError,
+    ) -> Result<Self, MarinadeFinanceError> {
+        if buf.get(..LIST_HEADER_LEN) != Some(&discm[..]) {
+            return Err(invalid_list);
+        }
+        let item_size = list.item_size as usize;
+        if item_size < record_len {
+            return Err(invalid_list);
+        }
+        let items_len = item_size
+            .checked_mul(list.count as usize)
+            .ok_or(invalid_list)?;
+        let items = buf[LIST_HEADER_LEN..]
+            .get(..items_len)
+            .ok_or(invalid_list)?;
+        Ok(Self {
+            items,
+            item_size,
+            count: list.count,
+            invalid_list,
+            record: PhantomData,
+        })
+    }
+    pub fn len(&self) -> usize {
+        self.count as usize
+    }
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+    pub fn get_raw(&self, index: u32) -> Result<&'a [u8], MarinadeFinanceError> {
+        if index >= self.count {
+            return Err(MarinadeFinanceError::ListIndexOutOfBounds);
+        }
+        let start = index as usize * self.item_size;
+        Ok(&self.items[start..start + self.item_size])
+    }
+    pub fn get(&self, index: u32) -> Result<T, MarinadeFinanceError> {
+        let mut item = self.get_raw(index)?;
+        T::deserialize(&mut item).map_err(|_| self.invalid_list)
+    }
+    pub fn iter(&self) -> ListViewIter<'a, T> {
+        ListViewIter {
+            view: *self,
+            index: 0,
+        }
+    }
+}
+impl<'a, T: BorshDeserialize> IntoIterator for ListView<'a, T> {
+    type Item = Result<(u32, T), MarinadeFinanceError>;
+    type IntoIter = ListViewIter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+#[derive(Debug)]
+pub struct ListViewIter<'a, T> {
+    view: ListView<'a, T>,
+    index: u32,
+}
+impl<T> Clone for ListViewIter<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            view: self.view,
+            index: self.index,
+        }
+    }
+}
+impl<T: BorshDeserialize> Iterator for ListViewIter<'_, T> {
+    type Item = Result<(u32, T), MarinadeFinanceError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.view.count {
+            return None;
+        }
+        let index = self.index;
+        self.index += 1;
+        Some(self.view.get(index).map(|record| (index, record)))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.view.count - self.index) as usize;
+        (remaining, Some(remaining))
+    }
+}
+impl<T: BorshDeserialize> ExactSizeIterator for ListViewIter<'_, T> {}
+impl StakeList {
+    pub fn view<'a>(buf: &'a [u8], list: &List) -> Result<StakeListView<'a>, MarinadeFinanceError> {
+        ListView::new(
+            buf,
+            list,
+            STAKE_LIST_DISCM,
+            STAKE_RECORD_LEN,
+            MarinadeFinanceError::InvalidStakeListDiscriminator,
+        )
+    }
+    pub fn deserialize_records(
+        buf: &[u8],
+        list: &List,
+    ) -> Result<Vec<(u32, StakeRecord)>, MarinadeFinanceError> {
+        Self::view(buf, list)?.iter().collect()
+    }
+}
+impl ValidatorList {
+    pub fn view<'a>(
+        buf: &'a [u8],
+        list: &List,
+    ) -> Result<ValidatorListView<'a>, MarinadeFinanceError> {
+        ListView::new(
+            buf,
+            list,
+            VALIDATOR_LIST_DISCM,
+            VALIDATOR_RECORD_LEN,
+            MarinadeFinanceError::InvalidValidatorListDiscriminator,
+        )
+    }
+    pub fn deserialize_records(
+        buf: &[u8],
+        list: &List,
+    ) -> Result<Vec<(u32, ValidatorRecord)>, MarinadeFinanceError> {
+        Self::view(buf, list)?.iter().collect()
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::zeroed;
+    use borsh::BorshSerialize;
+    use solana_program::pubkey::Pubkey;
+    fn stake_record(seed: u64) -> StakeRecord {
+        StakeRecord {
+            stake_account: Pubkey::new_unique(),
+            last_update_delegated_lamports: seed,
+            last_update_epoch: seed + 1,
+            is_emergency_unstaking: 0,
+        }
+    }
+    fn stake_list_buf(records: &[StakeRecord], item_size: usize) -> (Vec<u8>, List) {
+        let mut buf = STAKE_LIST_DISCM.to_vec();
+        for record in records {
+            let start = buf.len();
+            record.serialize(&mut buf).unwrap();
+            buf.resize(start + item_size, 0xff);
+        }
+        let mut list: List = zeroed();
+        list.item_size = item_size as u32;
+        list.count = records.len() as u32;
+        (buf, list)
+    }
+    #[test]
+    fn record_lens_match_borsh() {
+        assert_eq!(
+            stake_record(0).try_to_vec().unwrap().len(),
+            STAKE_RECORD_LEN
+        );
+        let validator: ValidatorRecord = zeroed();
+        assert_eq!(validator.try_to_vec().unwrap().len(), VALIDATOR_RECORD_LEN);
+    }
+    #[test]
+    fn padded_records() {
+        let records = [stake_record(1), stake_record(2), stake_record(3)];
+        let (buf, list) = stake_list_buf(&records, STAKE_RECORD_LEN + 15);
+        let view = StakeList::view(&buf, &list).unwrap();
+        assert_eq!(view.len(), 3);
+        assert_eq!(view.get(1).unwrap(), records[1]);
+        assert_eq!(view.get_raw(2).unwrap().len(), STAKE_RECORD_LEN + 15);
+        assert_eq!(
+            view.get(3).unwrap_err(),
+            MarinadeFinanceError::ListIndexOutOfBounds
+        );
+        let parsed = StakeList::deserialize_records(&buf, &list).unwrap();
+        assert_eq!(
+            parsed,
+            vec![
+                (0, records[0].clone()),
+                (1, records[1].clone()),
+                (2, records[2].clone()),
+            ]
+        );
+    }
+    #[test]
+    fn wrong_discriminator() {
+        let (mut buf, list) = stake_list_buf(&[stake_record(1)], STAKE_RECORD_LEN);
+        assert_eq!(
+            ValidatorList::view(&buf, &list).unwrap_err(),
+            MarinadeFinanceError::InvalidValidatorListDiscriminator
+        );
+        buf[0] ^= 1;
+        assert_eq!(
+            StakeList::view(&buf, &list).unwrap_err(),
+            MarinadeFinanceError::InvalidStakeListDiscriminator
+        );
+    }
+    #[test]
+    fn item_size_smaller_than_record() {
+        let (buf, mut list) = stake_list_buf(&[stake_record(1)], STAKE_RECORD_LEN);
+        list.item_size -= 1;
+        assert_eq!(
+            StakeList::view(&buf, &list).unwrap_err(),
+            MarinadeFinanceError::InvalidStakeListDiscriminator
+        );
+    }
+    #[test]
+    fn truncated_buffer() {
+        let (buf, list) = stake_list_buf(&[stake_record(1), stake_record(2)], STAKE_RECORD_LEN);
+        assert_eq!(
+            StakeList::view(&buf[..buf.len() - 1], &list).unwrap_err(),
+            MarinadeFinanceError::InvalidStakeListDiscriminator
+        );
+    }
+}
@@ -0,0 +1,284 @@
+use crate::*;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use solana_program::pubkey::Pubkey;
+use std::str::FromStr;
+pub const PROGRAM_LOG_PREFIX: &str = "Program ";
+pub const PROGRAM_DATA_LOG_PREFIX: &str = "Program data: ";
+#[derive(Clone, Debug)]
+pub struct EventsFromLogs<I> {
+    logs: I,
+    invoke_stack: Vec<bool>,
+}
+pub fn parse_events_from_logs<I>(logs: I) -> EventsFromLogs<I::IntoIter>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    EventsFromLogs {
+        logs: logs.into_iter(),
+        invoke_stack: Vec::new(),
+    }
+}
+impl<I> Iterator for EventsFromLogs<I>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    type Item = std::io::Result<MarinadeFinanceEvent>;
+    fn next(&mut self) -> Option<Self::Item> {
+        for line in self.logs.by_ref() {
+            let line = line.as_ref();
+            if let Some(data) = line.strip_prefix(PROGRAM_DATA_LOG_PREFIX) {
+                if self.invoke_stack.last() == Some(&true) {
+                    return Some(decode_event(data));
+                }
+                continue;
+            }
+            let Some(rest) = line.strip_prefix(PROGRAM_LOG_PREFIX) else {
+                continue;
+            };
+            let mut words = rest.split_whitespace();
+            let (Some(program), Some(action)) = (words.next(), words.next()) else {
+                continue;
+            };
+            let Ok(program_id) = Pubkey::from_str(program) else {
+                continue;
+            };
+            match action {
+                "invoke" => self.invoke_stack.push(program_id == crate::ID),
+                "success" | "failed:" => {
+                    self.invoke_stack.pop();
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+}
+fn decode_event(data: &str) -> std::io::Result<MarinadeFinanceEvent> {
+    let bytes = STANDARD
+        .decode(data.trim())
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    MarinadeFinanceEvent::deserialize(&mut bytes.as_slice())
+}
+pub const ANCHOR_ERROR_LOG_PREFIX: &str = "AnchorError ";
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AnchorErrorOrigin {
+    Account(String),
+    Source { file: String, line: u32 },
+}
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AnchorErrorLog {
+    pub error_code: String,
+    pub error_number: u32,
+    pub error_message: String,
+    pub origin: Option<AnchorErrorOrigin>,
+}
+impl AnchorErrorLog {
+    pub fn error(&self) -> Option<MarinadeFinanceProgramError> {
+        MarinadeFinanceProgramError::from_code(self.error_number)
+    }
+}
+pub fn parse_anchor_error_log(line: &str) -> Option<AnchorErrorLog> {
+    let line = line.strip_prefix("Program log: ").unwrap_or(line);
+    let rest = line.strip_prefix(ANCHOR_ERROR_LOG_PREFIX)?;
+    let (origin, rest) = rest.split_once(" Error Code: ")?;
+    let (error_code, rest) = rest.split_once(". Error Number: ")?;
+    let (error_number, error_message) = rest.split_once(". Error Message: ")?;
+    let origin = if origin == "occurred." {
+        None
+    } else if let Some(account) = origin.strip_prefix("caused by account: ") {
+        Some(AnchorErrorOrigin::Account(
+            account.strip_suffix('.')?.to_owned(),
+        ))
+    } else {
+        let (file, line) = origin
+            .strip_prefix("thrown in ")?
+            .strip_suffix('.')?
+            .rsplit_once(':')?;
+        Some(AnchorErrorOrigin::Source {
+            file: file.to_owned(),
+            line: line.parse().ok()?,
+        })
+    };
+    Some(AnchorErrorLog {
+        error_code: error_code.to_owned(),
+        error_number: error_number.parse().ok()?,
+        error_message: error_message
+            .strip_suffix('.')
+            .unwrap_or(error_message)
+            .to_owned(),
+        origin,
+    })
+}
+pub fn find_anchor_error_log<I>(logs: I) -> Option<AnchorErrorLog>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    logs.into_iter()
+        .find_map(|line| parse_anchor_error_log(line.as_ref()))
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::patterned;
+    use borsh::BorshSerialize;
+    fn invoke(program: &Pubkey, depth: usize) -> String {
+        format!("Program {} invoke [{}]", program, depth)
+    }
+    fn success(program: &Pubkey) -> String {
+        format!("Program {} success", program)
+    }
+    fn data(event: &MarinadeFinanceEvent) -> String {
+        format!(
+            "{}{}",
+            PROGRAM_DATA_LOG_PREFIX,
+            STANDARD.encode(event.try_to_vec().unwrap())
+        )
+    }
+    fn events(logs: &[String]) -> Vec<MarinadeFinanceEvent> {
+        parse_events_from_logs(logs)
+            .collect::<std::io::Result<_>>()
+            .unwrap()
+    }
+    #[test]
+    fn nested_cpi() {
+        let other = Pubkey::new_unique();
+        let deposit = MarinadeFinanceEvent::Deposit(patterned());
+        let claim = MarinadeFinanceEvent::Claim(patterned());
+        let other_data = data(&MarinadeFinanceEvent::Resume(patterned()));
+        let logs = [
+            invoke(&crate::ID, 1),
+            invoke(&other, 2),
+            other_data.clone(),
+            success(&other),
+            data(&deposit),
+            success(&crate::ID),
+            invoke(&other, 1),
+            other_data.clone(),
+            invoke(&crate::ID, 2),
+            "Program log: Instruction: Claim".to_owned(),
+            data(&claim),
+            success(&crate::ID),
+            other_data,
+            success(&other),
+        ];
+        assert_eq!(events(&logs), vec![deposit, claim]);
+    }
+    #[test]
+    fn failed_pops() {
+        let other = Pubkey::new_unique();
+        let deposit = MarinadeFinanceEvent::Deposit(patterned());
+        let logs = [
+            invoke(&other, 1),
+            invoke(&crate::ID, 2),
+            data(&deposit),
+            format!("Program {} failed: custom program error: 0x1771", crate::ID),
+            data(&MarinadeFinanceEvent::Resume(patterned())),
+            format!("Program {} failed: custom program error: 0x1771", other),
+        ];
+        assert_eq!(events(&logs), vec![deposit]);
+    }
+    #[test]
+    fn invalid_data() {
+        let mut unknown = MarinadeFinanceEvent::Claim(patterned())
+            .try_to_vec()
+            .unwrap();
+        unknown[..8].copy_from_slice(&[0; 8]);
+        let logs = [
+            invoke(&crate::ID, 1),
+            format!("{}not base64!", PROGRAM_DATA_LOG_PREFIX),
+            format!("{}{}", PROGRAM_DATA_LOG_PREFIX, STANDARD.encode(unknown)),
+            success(&crate::ID),
+        ];
+        let results: Vec<_> = parse_events_from_logs(&logs).collect();
+        assert_eq!(results.len(), 2);
+        let err = results[0].as_ref().unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        let err = results[1].as_ref().unwrap_err();
+        assert_eq!(err.to_string(), format!("discm {:?} not found", [0u8; 8]));
+    }
+    #[test]
+    fn anchor_error_occurred() {
+        let log = parse_anchor_error_log(
+            "Program log: AnchorError occurred. Error Code: TicketNotReady. Error Number: 6021. Error Message: Ticket not ready. Wait a few hours and try again.",
+        )
+        .unwrap();
+        assert_eq!(
+            log,
+            AnchorErrorLog {
+                error_code: "TicketNotReady".to_owned(),
+                error_number: 6021,
+                error_message: "Ticket not ready. Wait a few hours and try again".to_owned(),
+                origin: None,
+            }
+        );
+        assert_eq!(
+            log.error(),
+            Some(MarinadeFinanceProgramError::Marinade(
+                MarinadeFinanceError::TicketNotReady
+            ))
+        );
+    }
+    #[test]
+    fn anchor_error_caused_by_account() {
+        let log = parse_anchor_error_log(
+            "AnchorError caused by account: state. Error Code: AccountNotInitialized. Error Number: 3012. Error Message: The program expected this account to be already initialized.",
+        )
+        .unwrap();
+        assert_eq!(
+            log.origin,
+            Some(AnchorErrorOrigin::Account("state".to_owned()))
+        );
+        assert_eq!(log.error_code, "AccountNotInitialized");
+        assert_eq!(
+            log.error(),
+            Some(MarinadeFinanceProgramError::Anchor(
+                AnchorFrameworkError::AccountNotInitialized
+            ))
+        );
+    }
+    #[test]
+    fn anchor_error_thrown_in_source() {
+        let logs = [
+            format!("Program {} invoke [1]", crate::ID),
+            "Program log: Instruction: Deposit".to_owned(),
+            "Program log: AnchorError thrown in programs/marinade-finance/src/instructions/user/deposit.rs:58. Error Code: DepositAmountIsTooLow. Error Number: 6039. Error Message: Deposit amount is too low.".to_owned(),
+        ];
+        let log = find_anchor_error_log(&logs).unwrap();
+        assert_eq!(
+            log.origin,
+            Some(AnchorErrorOrigin::Source {
+                file: "programs/marinade-finance/src/instructions/user/deposit.rs".to_owned(),
+                line: 58,
+            })
+        );
+        assert_eq!(log.error_message, "Deposit amount is too low");
+        assert_eq!(
+            log.error(),
+            Some(MarinadeFinanceProgramError::Marinade(
+                MarinadeFinanceError::DepositAmountIsTooLow
+            ))
+        );
+    }
+    #[test]
+    fn anchor_error_malformed() {
+        assert_eq!(
+            parse_anchor_error_log(
+                "Program log: AnchorError occurred. Error Code: TicketNotReady. Error Number: six. Error Message: Ticket not ready."
+            ),
+            None
+        );
+        assert_eq!(
+            parse_anchor_error_log(
+                "Program log: AnchorError thrown in deposit.rs. Error Code: DepositAmountIsTooLow. Error Number: 6039. Error Message: Deposit amount is too low."
+            ),
+            None
+        );
+        assert_eq!(
+            parse_anchor_error_log("Program log: Instruction: Deposit"),
+            None
+        );
+    }
+}
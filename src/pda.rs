@@ -0,0 +1,148 @@
+use crate::*;
+use solana_program::pubkey::{Pubkey, PubkeyError};
+pub const RESERVE_PDA_SEED: &[u8] = b"reserve";
+pub const MSOL_MINT_AUTHORITY_SEED: &[u8] = b"st_mint";
+pub const LIQ_POOL_SOL_LEG_PDA_SEED: &[u8] = b"liq_sol";
+pub const LIQ_POOL_MSOL_LEG_AUTHORITY_SEED: &[u8] = b"liq_st_sol_authority";
+pub const LP_MINT_AUTHORITY_SEED: &[u8] = b"liq_mint";
+pub const STAKE_DEPOSIT_AUTHORITY_SEED: &[u8] = b"deposit";
+pub const STAKE_WITHDRAW_AUTHORITY_SEED: &[u8] = b"withdraw";
+pub const DUPLICATION_FLAG_SEED: &[u8] = b"unique_validator";
+pub fn find_reserve_pda(state: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[state.as_ref(), RESERVE_PDA_SEED], &crate::ID)
+}
+pub fn create_reserve_pda_with_bump(state: &Pubkey, bump: u8) -> Result<Pubkey, PubkeyError> {
+    Pubkey::create_program_address(&[state.as_ref(), RESERVE_PDA_SEED, &[bump]], &crate::ID)
+}
+pub fn find_msol_mint_authority(state: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[state.as_ref(), MSOL_MINT_AUTHORITY_SEED], &crate::ID)
+}
+pub fn create_msol_mint_authority_with_bump(
+    state: &Pubkey,
+    bump: u8,
+) -> Result<Pubkey, PubkeyError> {
+    Pubkey::create_program_address(
+        &[state.as_ref(), MSOL_MINT_AUTHORITY_SEED, &[bump]],
+        &crate::ID,
+    )
+}
+pub fn find_liq_pool_sol_leg_pda(state: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[state.as_ref(), LIQ_POOL_SOL_LEG_PDA_SEED], &crate::ID)
+}
+pub fn create_liq_pool_sol_leg_pda_with_bump(
+    state: &Pubkey,
+    bump: u8,
+) -> Result<Pubkey, PubkeyError> {
+    Pubkey::create_program_address(
+        &[state.as_ref(), LIQ_POOL_SOL_LEG_PDA_SEED, &[bump]],
+        &crate::ID,
+    )
+}
+pub fn find_liq_pool_msol_leg_authority(state: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[state.as_ref(), LIQ_POOL_MSOL_LEG_AUTHORITY_SEED],
+        &crate::ID,
+    )
+}
+pub fn create_liq_pool_msol_leg_authority_with_bump(
+    state: &Pubkey,
+    bump: u8,
+) -> Result<Pubkey, PubkeyError> {
+    Pubkey::create_program_address(
+        &[state.as_ref(), LIQ_POOL_MSOL_LEG_AUTHORITY_SEED, &[bump]],
+        &crate::ID,
+    )
+}
+pub fn find_lp_mint_authority(state: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[state.as_ref(), LP_MINT_AUTHORITY_SEED], &crate::ID)
+}
+pub fn create_lp_mint_authority_with_bump(state: &Pubkey, bump: u8) -> Result<Pubkey, PubkeyError> {
+    Pubkey::create_program_address(
+        &[state.as_ref(), LP_MINT_AUTHORITY_SEED, &[bump]],
+        &crate::ID,
+    )
+}
+pub fn find_stake_deposit_authority(state: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[state.as_ref(), STAKE_DEPOSIT_AUTHORITY_SEED], &crate::ID)
+}
+pub fn create_stake_deposit_authority_with_bump(
+    state: &Pubkey,
+    bump: u8,
+) -> Result<Pubkey, PubkeyError> {
+    Pubkey::create_program_address(
+        &[state.as_ref(), STAKE_DEPOSIT_AUTHORITY_SEED, &[bump]],
+        &crate::ID,
+    )
+}
+pub fn find_stake_withdraw_authority(state: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[state.as_ref(), STAKE_WITHDRAW_AUTHORITY_SEED], &crate::ID)
+}
+pub fn create_stake_withdraw_authority_with_bump(
+    state: &Pubkey,
+    bump: u8,
+) -> Result<Pubkey, PubkeyError> {
+    Pubkey::create_program_address(
+        &[state.as_ref(), STAKE_WITHDRAW_AUTHORITY_SEED, &[bump]],
+        &crate::ID,
+    )
+}
+pub fn find_duplication_flag(state: &Pubkey, validator_vote: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            state.as_ref(),
+            DUPLICATION_FLAG_SEED,
+            validator_vote.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+pub fn create_duplication_flag_with_bump(
+    state: &Pubkey,
+    validator_vote: &Pubkey,
+    bump: u8,
+) -> Result<Pubkey, PubkeyError> {
+    Pubkey::create_program_address(
+        &[
+            state.as_ref(),
+            DUPLICATION_FLAG_SEED,
+            validator_vote.as_ref(),
+            &[bump],
+        ],
+        &crate::ID,
+    )
+}
+impl State {
+    pub fn reserve_pda(&self, state: &Pubkey) -> Result<Pubkey, PubkeyError> {
+        create_reserve_pda_with_bump(state, self.reserve_bump_seed)
+    }
+    pub fn msol_mint_authority(&self, state: &Pubkey) -> Result<Pubkey, PubkeyError> {
+        create_msol_mint_authority_with_bump(state, self.msol_mint_authority_bump_seed)
+    }
+    pub fn liq_pool_sol_leg_pda(&self, state: &Pubkey) -> Result<Pubkey, PubkeyError> {
+        create_liq_pool_sol_leg_pda_with_bump(state, self.liq_pool.sol_leg_bump_seed)
+    }
+    pub fn liq_pool_msol_leg_authority(&self, state: &Pubkey) -> Result<Pubkey, PubkeyError> {
+        create_liq_pool_msol_leg_authority_with_bump(
+            state,
+            self.liq_pool.msol_leg_authority_bump_seed,
+        )
+    }
+    pub fn lp_mint_authority(&self, state: &Pubkey) -> Result<Pubkey, PubkeyError> {
+        create_lp_mint_authority_with_bump(state, self.liq_pool.lp_mint_authority_bump_seed)
+    }
+    pub fn stake_deposit_authority(&self, state: &Pubkey) -> Result<Pubkey, PubkeyError> {
+        create_stake_deposit_authority_with_bump(state, self.stake_system.stake_deposit_bump_seed)
+    }
+    pub fn stake_withdraw_authority(&self, state: &Pubkey) -> Result<Pubkey, PubkeyError> {
+        create_stake_withdraw_authority_with_bump(state, self.stake_system.stake_withdraw_bump_seed)
+    }
+}
+impl ValidatorRecord {
+    pub fn duplication_flag(&self, state: &Pubkey) -> Result<Pubkey, PubkeyError> {
+        create_duplication_flag_with_bump(
+            state,
+            &self.validator_account,
+            self.duplication_flag_bump_seed,
+        )
+    }
+}
@@ -0,0 +1,498 @@
+use crate::*;
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DepositQuote {
+    pub sol_swapped: u64,
+    pub msol_swapped: u64,
+    pub sol_deposited: u64,
+    pub msol_minted: u64,
+}
+impl DepositQuote {
+    pub fn msol_out(&self) -> u64 {
+        self.msol_swapped + self.msol_minted
+    }
+}
+pub fn quote_deposit(
+    state: &State,
+    liq_pool_msol_leg_balance: u64,
+    lamports: u64,
+) -> Result<DepositQuote, MarinadeFinanceError> {
+    if state.paused {
+        return Err(MarinadeFinanceError::ProgramIsPaused);
+    }
+    if lamports < state.min_deposit {
+        return Err(MarinadeFinanceError::DepositAmountIsTooLow);
+    }
+    let user_msol_buy_order = state.calc_msol_from_lamports(lamports)?;
+    let msol_swapped = user_msol_buy_order.min(liq_pool_msol_leg_balance);
+    let sol_swapped = if msol_swapped == 0 {
+        0
+    } else if msol_swapped == user_msol_buy_order {
+        lamports
+    } else {
+        state.msol_to_sol(msol_swapped)?
+    };
+    let sol_deposited = lamports
+        .checked_sub(sol_swapped)
+        .ok_or(MarinadeFinanceError::CalculationFailure)?;
+    let msol_minted = if sol_deposited > 0 {
+        state.check_staking_cap(sol_deposited)?;
+        user_msol_buy_order - msol_swapped
+    } else {
+        0
+    };
+    Ok(DepositQuote {
+        sol_swapped,
+        msol_swapped,
+        sol_deposited,
+        msol_minted,
+    })
+}
+#[derive(Clone, Debug, PartialEq)]
+pub struct LiquidUnstakeQuote {
+    pub sol_amount: u64,
+    pub msol_fee: u64,
+    pub treasury_msol_cut: u64,
+    pub fee: Fee,
+}
+pub fn quote_liquid_unstake(
+    state: &State,
+    liq_pool_sol_leg_lamports: u64,
+    msol_amount: u64,
+) -> Result<LiquidUnstakeQuote, MarinadeFinanceError> {
+    if state.paused {
+        return Err(MarinadeFinanceError::ProgramIsPaused);
+    }
+    let max_lamports = liq_pool_sol_leg_lamports.saturating_sub(state.rent_exempt_for_token_acc);
+    let user_remove_lamports = state.msol_to_sol(msol_amount)?;
+    let fee = if user_remove_lamports >= max_lamports {
+        state.liq_pool.lp_max_fee.clone()
+    } else {
+        state
+            .liq_pool
+            .linear_fee(max_lamports - user_remove_lamports)
+    };
+    let msol_fee = fee.apply(msol_amount)?;
+    let sol_amount = state.msol_to_sol(
+        msol_amount
+            .checked_sub(msol_fee)
+            .ok_or(MarinadeFinanceError::CalculationFailure)?,
+    )?;
+    let required_lamports = sol_amount
+        .checked_add(state.rent_exempt_for_token_acc)
+        .ok_or(MarinadeFinanceError::CalculationFailure)?;
+    if required_lamports > liq_pool_sol_leg_lamports {
+        return Err(MarinadeFinanceError::InsufficientLiquidity);
+    }
+    let treasury_msol_cut = state.liq_pool.treasury_cut.apply(msol_fee)?;
+    Ok(LiquidUnstakeQuote {
+        sol_amount,
+        msol_fee,
+        treasury_msol_cut,
+        fee,
+    })
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RemoveLiquidityQuote {
+    pub sol_out_amount: u64,
+    pub msol_out_amount: u64,
+}
+pub fn quote_add_liquidity(
+    state: &State,
+    lp_mint_supply: u64,
+    liq_pool_sol_leg_lamports: u64,
+    liq_pool_msol_leg_balance: u64,
+    lamports: u64,
+) -> Result<u64, MarinadeFinanceError> {
+    if state.paused {
+        return Err(MarinadeFinanceError::ProgramIsPaused);
+    }
+    if lamports < state.min_deposit {
+        return Err(MarinadeFinanceError::DepositAmountIsTooLow);
+    }
+    let lp_supply = checked_lp_supply(state, lp_mint_supply)?;
+    let sol_leg_balance = liq_pool_sol_leg_balance(state, liq_pool_sol_leg_lamports)?;
+    let total_liq_pool_value =
+        liq_pool_total_value(state, liq_pool_sol_leg_lamports, liq_pool_msol_leg_balance)?;
+    state
+        .liq_pool
+        .check_liquidity_cap(lamports, sol_leg_balance)?;
+    shares_from_value(lamports, total_liq_pool_value, lp_supply)
+}
+pub fn quote_remove_liquidity(
+    state: &State,
+    lp_mint_supply: u64,
+    liq_pool_sol_leg_lamports: u64,
+    liq_pool_msol_leg_balance: u64,
+    tokens: u64,
+) -> Result<RemoveLiquidityQuote, MarinadeFinanceError> {
+    if state.paused {
+        return Err(MarinadeFinanceError::ProgramIsPaused);
+    }
+    let lp_supply = checked_lp_supply(state, lp_mint_supply)?;
+    let sol_leg_balance = liq_pool_sol_leg_balance(state, liq_pool_sol_leg_lamports)?;
+    let sol_out_amount = proportional(tokens, sol_leg_balance, lp_supply)?;
+    let msol_out_amount = proportional(tokens, liq_pool_msol_leg_balance, lp_supply)?;
+    let out_value = sol_out_amount
+        .checked_add(state.msol_to_sol(msol_out_amount)?)
+        .ok_or(MarinadeFinanceError::CalculationFailure)?;
+    if out_value < state.min_withdraw {
+        return Err(MarinadeFinanceError::WithdrawAmountIsTooLow);
+    }
+    Ok(RemoveLiquidityQuote {
+        sol_out_amount,
+        msol_out_amount,
+    })
+}
+pub fn liq_pool_total_value(
+    state: &State,
+    liq_pool_sol_leg_lamports: u64,
+    liq_pool_msol_leg_balance: u64,
+) -> Result<u64, MarinadeFinanceError> {
+    liq_pool_sol_leg_balance(state, liq_pool_sol_leg_lamports)?
+        .checked_add(state.msol_to_sol(liq_pool_msol_leg_balance)?)
+        .ok_or(MarinadeFinanceError::CalculationFailure)
+}
+pub fn lp_to_sol(
+    state: &State,
+    lp_supply: u64,
+    liq_pool_sol_leg_lamports: u64,
+    liq_pool_msol_leg_balance: u64,
+    lp_tokens: u64,
+) -> Result<u64, MarinadeFinanceError> {
+    value_from_shares(
+        lp_tokens,
+        liq_pool_total_value(state, liq_pool_sol_leg_lamports, liq_pool_msol_leg_balance)?,
+        lp_supply,
+    )
+}
+pub fn calc_lp_price(
+    state: &State,
+    lp_supply: u64,
+    liq_pool_sol_leg_lamports: u64,
+    liq_pool_msol_leg_balance: u64,
+) -> Result<u64, MarinadeFinanceError> {
+    lp_to_sol(
+        state,
+        lp_supply,
+        liq_pool_sol_leg_lamports,
+        liq_pool_msol_leg_balance,
+        PRICE_DENOMINATOR,
+    )
+}
+fn checked_lp_supply(state: &State, lp_mint_supply: u64) -> Result<u64, MarinadeFinanceError> {
+    state.liq_pool.check_lp_supply(lp_mint_supply)?;
+    Ok(lp_mint_supply)
+}
+fn liq_pool_sol_leg_balance(
+    state: &State,
+    liq_pool_sol_leg_lamports: u64,
+) -> Result<u64, MarinadeFinanceError> {
+    liq_pool_sol_leg_lamports
+        .checked_sub(state.rent_exempt_for_token_acc)
+        .ok_or(MarinadeFinanceError::CalculationFailure)
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OrderUnstakeQuote {
+    pub ticket_lamports: u64,
+    pub fee_lamports: u64,
+}
+pub fn quote_order_unstake(
+    state: &State,
+    msol_amount: u64,
+) -> Result<OrderUnstakeQuote, MarinadeFinanceError> {
+    if state.paused {
+        return Err(MarinadeFinanceError::ProgramIsPaused);
+    }
+    let lamports_amount = state.msol_to_sol(msol_amount)?;
+    if lamports_amount < state.min_withdraw {
+        return Err(MarinadeFinanceError::WithdrawAmountIsTooLow);
+    }
+    let fee_lamports = state.delayed_unstake_fee.apply(lamports_amount)?;
+    Ok(OrderUnstakeQuote {
+        ticket_lamports: lamports_amount
+            .checked_sub(fee_lamports)
+            .ok_or(MarinadeFinanceError::CalculationFailure)?,
+        fee_lamports,
+    })
+}
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WithdrawStakeAccountQuote {
+    pub msol_burned: u64,
+    pub msol_fees: u64,
+    pub split_lamports: u64,
+}
+pub fn quote_withdraw_stake_account(
+    state: &State,
+    msol_amount: u64,
+) -> Result<WithdrawStakeAccountQuote, MarinadeFinanceError> {
+    if state.paused {
+        return Err(MarinadeFinanceError::ProgramIsPaused);
+    }
+    if !state.withdraw_stake_account_enabled {
+        return Err(MarinadeFinanceError::WithdrawStakeAccountIsNotEnabled);
+    }
+    let msol_fees = state.withdraw_stake_account_fee.apply(msol_amount)?;
+    let msol_burned = msol_amount
+        .checked_sub(msol_fees)
+        .ok_or(MarinadeFinanceError::CalculationFailure)?;
+    let split_lamports = state.msol_to_sol(msol_burned)?;
+    if split_lamports < state.stake_system.min_stake {
+        return Err(MarinadeFinanceError::WithdrawStakeLamportsIsTooLow);
+    }
+    Ok(WithdrawStakeAccountQuote {
+        msol_burned,
+        msol_fees,
+        split_lamports,
+    })
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{quote_state, TEST_RENT_EXEMPT};
+    #[test]
+    fn deposit_without_pool_liquidity_mints_everything() {
+        let quote = quote_deposit(&quote_state(), 0, 1_000_000_000).unwrap();
+        assert_eq!(
+            quote,
+            DepositQuote {
+                sol_swapped: 0,
+                msol_swapped: 0,
+                sol_deposited: 1_000_000_000,
+                msol_minted: 800_000_000,
+            }
+        );
+    }
+    #[test]
+    fn deposit_fully_filled_from_pool() {
+        let mut state = quote_state();
+        state.staking_sol_cap = 0;
+        let quote = quote_deposit(&state, 10_000_000_000, 1_000_000_000).unwrap();
+        assert_eq!(
+            quote,
+            DepositQuote {
+                sol_swapped: 1_000_000_000,
+                msol_swapped: 800_000_000,
+                sol_deposited: 0,
+                msol_minted: 0,
+            }
+        );
+    }
+    #[test]
+    fn deposit_partially_filled_from_pool() {
+        let quote = quote_deposit(&quote_state(), 300_000_000, 1_000_000_000).unwrap();
+        assert_eq!(
+            quote,
+            DepositQuote {
+                sol_swapped: 375_000_000,
+                msol_swapped: 300_000_000,
+                sol_deposited: 625_000_000,
+                msol_minted: 500_000_000,
+            }
+        );
+        assert_eq!(quote.msol_out(), 800_000_000);
+    }
+    #[test]
+    fn deposit_staking_cap_applies_to_deposited_part() {
+        let mut state = quote_state();
+        state.staking_sol_cap = state.total_lamports_under_control().unwrap() + 625_000_000;
+        assert!(quote_deposit(&state, 300_000_000, 1_000_000_000).is_ok());
+        state.staking_sol_cap -= 1;
+        assert_eq!(
+            quote_deposit(&state, 300_000_000, 1_000_000_000).unwrap_err(),
+            MarinadeFinanceError::StakingIsCapped
+        );
+    }
+    #[test]
+    fn deposit_checks_pause_and_minimum() {
+        let mut state = quote_state();
+        state.min_deposit = 1_000;
+        assert_eq!(
+            quote_deposit(&state, 0, 999).unwrap_err(),
+            MarinadeFinanceError::DepositAmountIsTooLow
+        );
+        state.paused = true;
+        assert_eq!(
+            quote_deposit(&state, 0, 1_000).unwrap_err(),
+            MarinadeFinanceError::ProgramIsPaused
+        );
+    }
+    #[test]
+    fn liquid_unstake_with_deep_pool_pays_min_fee() {
+        let state = quote_state();
+        let quote =
+            quote_liquid_unstake(&state, TEST_RENT_EXEMPT + 20_000_000_000_000, 800_000_000)
+                .unwrap();
+        assert_eq!(
+            quote,
+            LiquidUnstakeQuote {
+                sol_amount: 997_000_000,
+                msol_fee: 2_400_000,
+                treasury_msol_cut: 600_000,
+                fee: Fee::from_basis_points(30),
+            }
+        );
+    }
+    #[test]
+    fn liquid_unstake_draining_pool_pays_max_fee() {
+        let state = quote_state();
+        let quote =
+            quote_liquid_unstake(&state, TEST_RENT_EXEMPT + 1_212_500_000, 1_000_000_000).unwrap();
+        assert_eq!(quote.fee, Fee::from_basis_points(300));
+        assert_eq!(quote.msol_fee, 30_000_000);
+        assert_eq!(quote.sol_amount, 1_212_500_000);
+    }
+    #[test]
+    fn liquid_unstake_insufficient_liquidity_boundary() {
+        let state = quote_state();
+        assert!(
+            quote_liquid_unstake(&state, TEST_RENT_EXEMPT + 1_212_500_000, 1_000_000_000).is_ok()
+        );
+        assert_eq!(
+            quote_liquid_unstake(&state, TEST_RENT_EXEMPT + 1_212_499_999, 1_000_000_000)
+                .unwrap_err(),
+            MarinadeFinanceError::InsufficientLiquidity
+        );
+    }
+    #[test]
+    fn add_liquidity_to_empty_pool_is_one_to_one() {
+        let mut state = quote_state();
+        state.liq_pool.lp_supply = 0;
+        assert_eq!(
+            quote_add_liquidity(&state, 0, TEST_RENT_EXEMPT, 0, 1_000_000_000).unwrap(),
+            1_000_000_000
+        );
+    }
+    #[test]
+    fn add_liquidity_mints_proportional_lp() {
+        let mut state = quote_state();
+        state.liq_pool.lp_supply = 1_000_000_000_000;
+        assert_eq!(
+            quote_add_liquidity(
+                &state,
+                1_000_000_000_000,
+                TEST_RENT_EXEMPT + 1_000_000_000_000,
+                800_000_000_000,
+                1_000_000_000
+            )
+            .unwrap(),
+            500_000_000
+        );
+    }
+    #[test]
+    fn add_liquidity_cap() {
+        let mut state = quote_state();
+        state.liq_pool.lp_supply = 1_000_000_000_000;
+        state.liq_pool.liquidity_sol_cap = 1_001_000_000_000;
+        let quote = |state: &State| {
+            quote_add_liquidity(
+                state,
+                1_000_000_000_000,
+                TEST_RENT_EXEMPT + 1_000_000_000_000,
+                800_000_000_000,
+                1_000_000_000,
+            )
+        };
+        assert!(quote(&state).is_ok());
+        state.liq_pool.liquidity_sol_cap -= 1;
+        assert_eq!(
+            quote(&state).unwrap_err(),
+            MarinadeFinanceError::LiquidityIsCapped
+        );
+    }
+    #[test]
+    fn unregistered_lp_minted() {
+        let mut state = quote_state();
+        state.liq_pool.lp_supply = 1_000_000_000_000;
+        assert_eq!(
+            quote_add_liquidity(
+                &state,
+                1_000_000_000_001,
+                TEST_RENT_EXEMPT,
+                0,
+                1_000_000_000
+            )
+            .unwrap_err(),
+            MarinadeFinanceError::UnregisteredLpMinted
+        );
+        assert_eq!(
+            quote_remove_liquidity(
+                &state,
+                1_000_000_000_001,
+                TEST_RENT_EXEMPT,
+                0,
+                1_000_000_000
+            )
+            .unwrap_err(),
+            MarinadeFinanceError::UnregisteredLpMinted
+        );
+    }
+    #[test]
+    fn remove_liquidity_min_withdraw_boundary() {
+        let mut state = quote_state();
+        state.liq_pool.lp_supply = 1_000_000_000_000;
+        state.min_withdraw = 2_000_000_000;
+        let quote = |state: &State| {
+            quote_remove_liquidity(
+                state,
+                1_000_000_000_000,
+                TEST_RENT_EXEMPT + 1_000_000_000_000,
+                800_000_000_000,
+                1_000_000_000,
+            )
+        };
+        assert_eq!(
+            quote(&state).unwrap(),
+            RemoveLiquidityQuote {
+                sol_out_amount: 1_000_000_000,
+                msol_out_amount: 800_000_000,
+            }
+        );
+        state.min_withdraw += 1;
+        assert_eq!(
+            quote(&state).unwrap_err(),
+            MarinadeFinanceError::WithdrawAmountIsTooLow
+        );
+    }
+    #[test]
+    fn order_unstake_fee_and_min_withdraw() {
+        let mut state = quote_state();
+        state.delayed_unstake_fee = FeeCents::from_bp_cents(1_000);
+        state.min_withdraw = 1_000_000_000;
+        assert_eq!(
+            quote_order_unstake(&state, 800_000_000).unwrap(),
+            OrderUnstakeQuote {
+                ticket_lamports: 999_000_000,
+                fee_lamports: 1_000_000,
+            }
+        );
+        state.min_withdraw += 1;
+        assert_eq!(
+            quote_order_unstake(&state, 800_000_000).unwrap_err(),
+            MarinadeFinanceError::WithdrawAmountIsTooLow
+        );
+    }
+    #[test]
+    fn withdraw_stake_account_fee_and_min_stake() {
+        let mut state = quote_state();
+        state.withdraw_stake_account_fee = FeeCents::from_bp_cents(2_000);
+        state.stake_system.min_stake = 1_247_500_000;
+        assert_eq!(
+            quote_withdraw_stake_account(&state, 1_000_000_000).unwrap_err(),
+            MarinadeFinanceError::WithdrawStakeAccountIsNotEnabled
+        );
+        state.withdraw_stake_account_enabled = true;
+        assert_eq!(
+            quote_withdraw_stake_account(&state, 1_000_000_000).unwrap(),
+            WithdrawStakeAccountQuote {
+                msol_burned: 998_000_000,
+                msol_fees: 2_000_000,
+                split_lamports: 1_247_500_000,
+            }
+        );
+        state.stake_system.min_stake += 1;
+        assert_eq!(
+            quote_withdraw_stake_account(&state, 1_000_000_000).unwrap_err(),
+            MarinadeFinanceError::WithdrawStakeLamportsIsTooLow
+        );
+    }
+}
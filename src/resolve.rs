@@ -0,0 +1,572 @@
+use crate::*;
+use solana_program::{
+    pubkey,
+    pubkey::{Pubkey, PubkeyError},
+    stake, system_program, sysvar,
+};
+pub const TOKEN_PROGRAM_ID: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+pub const STAKE_CONFIG_ID: Pubkey = pubkey!("StakeConfig11111111111111111111111111111111");
+impl DepositKeys {
+    pub fn resolve(
+        state_addr: Pubkey,
+        state: &State,
+        transfer_from: Pubkey,
+        mint_to: Pubkey,
+    ) -> Result<Self, PubkeyError> {
+        Ok(Self {
+            state: state_addr,
+            msol_mint: state.msol_mint,
+            liq_pool_sol_leg_pda: state.liq_pool_sol_leg_pda(&state_addr)?,
+            liq_pool_msol_leg: state.liq_pool.msol_leg,
+            liq_pool_msol_leg_authority: state.liq_pool_msol_leg_authority(&state_addr)?,
+            reserve_pda: state.reserve_pda(&state_addr)?,
+            transfer_from,
+            mint_to,
+            msol_mint_authority: state.msol_mint_authority(&state_addr)?,
+            system_program: system_program::ID,
+            token_program: TOKEN_PROGRAM_ID,
+        })
+    }
+}
+impl LiquidUnstakeKeys {
+    pub fn resolve(
+        state_addr: Pubkey,
+        state: &State,
+        get_msol_from: Pubkey,
+        get_msol_from_authority: Pubkey,
+        transfer_sol_to: Pubkey,
+    ) -> Result<Self, PubkeyError> {
+        Ok(Self {
+            state: state_addr,
+            msol_mint: state.msol_mint,
+            liq_pool_sol_leg_pda: state.liq_pool_sol_leg_pda(&state_addr)?,
+            liq_pool_msol_leg: state.liq_pool.msol_leg,
+            treasury_msol_account: state.treasury_msol_account,
+            get_msol_from,
+            get_msol_from_authority,
+            transfer_sol_to,
+            system_program: system_program::ID,
+            token_program: TOKEN_PROGRAM_ID,
+        })
+    }
+}
+impl OrderUnstakeKeys {
+    pub fn resolve(
+        state_addr: Pubkey,
+        state: &State,
+        burn_msol_from: Pubkey,
+        burn_msol_authority: Pubkey,
+        new_ticket_account: Pubkey,
+    ) -> Self {
+        Self {
+            state: state_addr,
+            msol_mint: state.msol_mint,
+            burn_msol_from,
+            burn_msol_authority,
+            new_ticket_account,
+            clock: sysvar::clock::ID,
+            rent: sysvar::rent::ID,
+            token_program: TOKEN_PROGRAM_ID,
+        }
+    }
+}
+impl ClaimKeys {
+    pub fn resolve(
+        state_addr: Pubkey,
+        state: &State,
+        ticket_account: Pubkey,
+        transfer_sol_to: Pubkey,
+    ) -> Result<Self, PubkeyError> {
+        Ok(Self {
+            state: state_addr,
+            reserve_pda: state.reserve_pda(&state_addr)?,
+            ticket_account,
+            transfer_sol_to,
+            clock: sysvar::clock::ID,
+            system_program: system_program::ID,
+        })
+    }
+}
+impl AddLiquidityKeys {
+    pub fn resolve(
+        state_addr: Pubkey,
+        state: &State,
+        transfer_from: Pubkey,
+        mint_to: Pubkey,
+    ) -> Result<Self, PubkeyError> {
+        Ok(Self {
+            state: state_addr,
+            lp_mint: state.liq_pool.lp_mint,
+            lp_mint_authority: state.lp_mint_authority(&state_addr)?,
+            liq_pool_msol_leg: state.liq_pool.msol_leg,
+            liq_pool_sol_leg_pda: state.liq_pool_sol_leg_pda(&state_addr)?,
+            transfer_from,
+            mint_to,
+            system_program: system_program::ID,
+            token_program: TOKEN_PROGRAM_ID,
+        })
+    }
+}
+impl RemoveLiquidityKeys {
+    pub fn resolve(
+        state_addr: Pubkey,
+        state: &State,
+        burn_from: Pubkey,
+        burn_from_authority: Pubkey,
+        transfer_sol_to: Pubkey,
+        transfer_msol_to: Pubkey,
+    ) -> Result<Self, PubkeyError> {
+        Ok(Self {
+            state: state_addr,
+            lp_mint: state.liq_pool.lp_mint,
+            burn_from,
+            burn_from_authority,
+            transfer_sol_to,
+            transfer_msol_to,
+            liq_pool_sol_leg_pda: state.liq_pool_sol_leg_pda(&state_addr)?,
+            liq_pool_msol_leg: state.liq_pool.msol_leg,
+            liq_pool_msol_leg_authority: state.liq_pool_msol_leg_authority(&state_addr)?,
+            system_program: system_program::ID,
+            token_program: TOKEN_PROGRAM_ID,
+        })
+    }
+}
+impl WithdrawStakeAccountKeys {
+    pub fn resolve(
+        state_addr: Pubkey,
+        state: &State,
+        burn_msol_from: Pubkey,
+        burn_msol_authority: Pubkey,
+        stake_account: Pubkey,
+        split_stake_account: Pubkey,
+        split_stake_rent_payer: Pubkey,
+    ) -> Result<Self, PubkeyError> {
+        Ok(Self {
+            state: state_addr,
+            msol_mint: state.msol_mint,
+            burn_msol_from,
+            burn_msol_authority,
+            treasury_msol_account: state.treasury_msol_account,
+            validator_list: state.validator_system.validator_list.account,
+            stake_list: state.stake_system.stake_list.account,
+            stake_withdraw_authority: state.stake_withdraw_authority(&state_addr)?,
+            stake_deposit_authority: state.stake_deposit_authority(&state_addr)?,
+            stake_account,
+            split_stake_account,
+            split_stake_rent_payer,
+            clock: sysvar::clock::ID,
+            system_program: system_program::ID,
+            token_program: TOKEN_PROGRAM_ID,
+            stake_program: stake::program::ID,
+        })
+    }
+}
+impl StakeReserveKeys {
+    pub fn resolve(
+        state_addr: Pubkey,
+        state: &State,
+        validator_index: u32,
+        validator: &ValidatorRecord,
+        stake_account: Pubkey,
+        rent_payer: Pubkey,
+    ) -> Result<(Self, StakeReserveIxArgs), PubkeyError> {
+        let keys = Self {
+            state: state_addr,
+            validator_list: state.validator_system.validator_list.account,
+            stake_list: state.stake_system.stake_list.account,
+            validator_vote: validator.validator_account,
+            reserve_pda: state.reserve_pda(&state_addr)?,
+            stake_account,
+            stake_deposit_authority: state.stake_deposit_authority(&state_addr)?,
+            rent_payer,
+            clock: sysvar::clock::ID,
+            epoch_schedule: sysvar::epoch_schedule::ID,
+            rent: sysvar::rent::ID,
+            stake_history: sysvar::stake_history::ID,
+            stake_config: STAKE_CONFIG_ID,
+            system_program: system_program::ID,
+            stake_program: stake::program::ID,
+        };
+        Ok((keys, StakeReserveIxArgs { validator_index }))
+    }
+}
+impl UpdateActiveKeys {
+    pub fn resolve(
+        state_addr: Pubkey,
+        state: &State,
+        stake_index: u32,
+        stake: &StakeRecord,
+        validator_index: u32,
+    ) -> Result<(Self, UpdateActiveIxArgs), PubkeyError> {
+        let keys = Self {
+            common_state: state_addr,
+            common_stake_list: state.stake_system.stake_list.account,
+            common_stake_account: stake.stake_account,
+            common_stake_withdraw_authority: state.stake_withdraw_authority(&state_addr)?,
+            common_reserve_pda: state.reserve_pda(&state_addr)?,
+            common_msol_mint: state.msol_mint,
+            common_msol_mint_authority: state.msol_mint_authority(&state_addr)?,
+            common_treasury_msol_account: state.treasury_msol_account,
+            common_clock: sysvar::clock::ID,
+            common_stake_history: sysvar::stake_history::ID,
+            common_stake_program: stake::program::ID,
+            common_token_program: TOKEN_PROGRAM_ID,
+            validator_list: state.validator_system.validator_list.account,
+        };
+        Ok((
+            keys,
+            UpdateActiveIxArgs {
+                stake_index,
+                validator_index,
+            },
+        ))
+    }
+}
+impl UpdateDeactivatedKeys {
+    pub fn resolve(
+        state_addr: Pubkey,
+        state: &State,
+        stake_index: u32,
+        stake: &StakeRecord,
+    ) -> Result<(Self, UpdateDeactivatedIxArgs), PubkeyError> {
+        let keys = Self {
+            common_state: state_addr,
+            common_stake_list: state.stake_system.stake_list.account,
+            common_stake_account: stake.stake_account,
+            common_stake_withdraw_authority: state.stake_withdraw_authority(&state_addr)?,
+            common_reserve_pda: state.reserve_pda(&state_addr)?,
+            common_msol_mint: state.msol_mint,
+            common_msol_mint_authority: state.msol_mint_authority(&state_addr)?,
+            common_treasury_msol_account: state.treasury_msol_account,
+            common_clock: sysvar::clock::ID,
+            common_stake_history: sysvar::stake_history::ID,
+            common_stake_program: stake::program::ID,
+            common_token_program: TOKEN_PROGRAM_ID,
+            operational_sol_account: state.operational_sol_account,
+            system_program: system_program::ID,
+        };
+        Ok((keys, UpdateDeactivatedIxArgs { stake_index }))
+    }
+}
+impl DeactivateStakeKeys {
+    pub fn resolve(
+        state_addr: Pubkey,
+        state: &State,
+        stake_index: u32,
+        stake: &StakeRecord,
+        validator_index: u32,
+        split_stake_account: Pubkey,
+        split_stake_rent_payer: Pubkey,
+    ) -> Result<(Self, DeactivateStakeIxArgs), PubkeyError> {
+        let keys = Self {
+            state: state_addr,
+            reserve_pda: state.reserve_pda(&state_addr)?,
+            validator_list: state.validator_system.validator_list.account,
+            stake_list: state.stake_system.stake_list.account,
+            stake_account: stake.stake_account,
+            stake_deposit_authority: state.stake_deposit_authority(&state_addr)?,
+            split_stake_account,
+            split_stake_rent_payer,
+            clock: sysvar::clock::ID,
+            rent: sysvar::rent::ID,
+            epoch_schedule: sysvar::epoch_schedule::ID,
+            stake_history: sysvar::stake_history::ID,
+            system_program: system_program::ID,
+            stake_program: stake::program::ID,
+        };
+        Ok((
+            keys,
+            DeactivateStakeIxArgs {
+                stake_index,
+                validator_index,
+            },
+        ))
+    }
+}
+impl MergeStakesKeys {
+    pub fn resolve(
+        state_addr: Pubkey,
+        state: &State,
+        destination_stake_index: u32,
+        destination_stake: &StakeRecord,
+        source_stake_index: u32,
+        source_stake: &StakeRecord,
+        validator_index: u32,
+    ) -> Result<(Self, MergeStakesIxArgs), PubkeyError> {
+        let keys = Self {
+            state: state_addr,
+            stake_list: state.stake_system.stake_list.account,
+            validator_list: state.validator_system.validator_list.account,
+            destination_stake: destination_stake.stake_account,
+            source_stake: source_stake.stake_account,
+            stake_deposit_authority: state.stake_deposit_authority(&state_addr)?,
+            stake_withdraw_authority: state.stake_withdraw_authority(&state_addr)?,
+            operational_sol_account: state.operational_sol_account,
+            clock: sysvar::clock::ID,
+            stake_history: sysvar::stake_history::ID,
+            stake_program: stake::program::ID,
+        };
+        Ok((
+            keys,
+            MergeStakesIxArgs {
+                destination_stake_index,
+                source_stake_index,
+                validator_index,
+            },
+        ))
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{test_state, zeroed};
+    fn stake_record() -> StakeRecord {
+        let mut stake: StakeRecord = zeroed();
+        stake.stake_account = Pubkey::new_unique();
+        stake
+    }
+    #[test]
+    fn deposit_keys_use_derived_pdas() {
+        let state_addr = Pubkey::new_unique();
+        let state = test_state(&state_addr);
+        let (transfer_from, mint_to) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let keys = DepositKeys::resolve(state_addr, &state, transfer_from, mint_to).unwrap();
+        assert_eq!(keys.state, state_addr);
+        assert_eq!(keys.msol_mint, state.msol_mint);
+        assert_eq!(
+            keys.liq_pool_sol_leg_pda,
+            find_liq_pool_sol_leg_pda(&state_addr).0
+        );
+        assert_eq!(
+            keys.liq_pool_msol_leg_authority,
+            find_liq_pool_msol_leg_authority(&state_addr).0
+        );
+        assert_eq!(keys.reserve_pda, find_reserve_pda(&state_addr).0);
+        assert_eq!(
+            keys.msol_mint_authority,
+            find_msol_mint_authority(&state_addr).0
+        );
+        assert_eq!(keys.transfer_from, transfer_from);
+        assert_eq!(keys.mint_to, mint_to);
+    }
+    #[test]
+    fn liquid_unstake_keys_use_derived_pdas() {
+        let state_addr = Pubkey::new_unique();
+        let state = test_state(&state_addr);
+        let (from, authority, to) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let keys = LiquidUnstakeKeys::resolve(state_addr, &state, from, authority, to).unwrap();
+        assert_eq!(keys.state, state_addr);
+        assert_eq!(keys.msol_mint, state.msol_mint);
+        assert_eq!(
+            keys.liq_pool_sol_leg_pda,
+            find_liq_pool_sol_leg_pda(&state_addr).0
+        );
+        assert_eq!(keys.liq_pool_msol_leg, state.liq_pool.msol_leg);
+        assert_eq!(keys.treasury_msol_account, state.treasury_msol_account);
+        assert_eq!(keys.get_msol_from, from);
+        assert_eq!(keys.get_msol_from_authority, authority);
+        assert_eq!(keys.transfer_sol_to, to);
+        assert_eq!(keys.token_program, TOKEN_PROGRAM_ID);
+    }
+    #[test]
+    fn order_unstake_keys_fill_sysvars() {
+        let state_addr = Pubkey::new_unique();
+        let state = test_state(&state_addr);
+        let (from, authority, ticket) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let keys = OrderUnstakeKeys::resolve(state_addr, &state, from, authority, ticket);
+        assert_eq!(keys.msol_mint, state.msol_mint);
+        assert_eq!(keys.burn_msol_from, from);
+        assert_eq!(keys.burn_msol_authority, authority);
+        assert_eq!(keys.new_ticket_account, ticket);
+        assert_eq!(keys.clock, sysvar::clock::ID);
+        assert_eq!(keys.rent, sysvar::rent::ID);
+    }
+    #[test]
+    fn claim_keys_use_derived_pdas() {
+        let state_addr = Pubkey::new_unique();
+        let state = test_state(&state_addr);
+        let (ticket, to) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let keys = ClaimKeys::resolve(state_addr, &state, ticket, to).unwrap();
+        assert_eq!(keys.reserve_pda, find_reserve_pda(&state_addr).0);
+        assert_eq!(keys.ticket_account, ticket);
+        assert_eq!(keys.transfer_sol_to, to);
+        assert_eq!(keys.clock, sysvar::clock::ID);
+    }
+    #[test]
+    fn liquidity_keys_use_derived_pdas() {
+        let state_addr = Pubkey::new_unique();
+        let state = test_state(&state_addr);
+        let (from, to) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let add = AddLiquidityKeys::resolve(state_addr, &state, from, to).unwrap();
+        assert_eq!(add.lp_mint, state.liq_pool.lp_mint);
+        assert_eq!(add.lp_mint_authority, find_lp_mint_authority(&state_addr).0);
+        assert_eq!(
+            add.liq_pool_sol_leg_pda,
+            find_liq_pool_sol_leg_pda(&state_addr).0
+        );
+        assert_eq!(add.liq_pool_msol_leg, state.liq_pool.msol_leg);
+        assert_eq!((add.transfer_from, add.mint_to), (from, to));
+        let (burn_from, authority, sol_to, msol_to) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let remove =
+            RemoveLiquidityKeys::resolve(state_addr, &state, burn_from, authority, sol_to, msol_to)
+                .unwrap();
+        assert_eq!(remove.lp_mint, state.liq_pool.lp_mint);
+        assert_eq!(remove.liq_pool_sol_leg_pda, add.liq_pool_sol_leg_pda);
+        assert_eq!(
+            remove.liq_pool_msol_leg_authority,
+            find_liq_pool_msol_leg_authority(&state_addr).0
+        );
+        assert_eq!(
+            (remove.burn_from, remove.burn_from_authority),
+            (burn_from, authority)
+        );
+        assert_eq!(
+            (remove.transfer_sol_to, remove.transfer_msol_to),
+            (sol_to, msol_to)
+        );
+    }
+    #[test]
+    fn withdraw_stake_account_keys_use_derived_pdas() {
+        let state_addr = Pubkey::new_unique();
+        let state = test_state(&state_addr);
+        let accounts: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
+        let keys = WithdrawStakeAccountKeys::resolve(
+            state_addr,
+            &state,
+            accounts[0],
+            accounts[1],
+            accounts[2],
+            accounts[3],
+            accounts[4],
+        )
+        .unwrap();
+        assert_eq!(keys.treasury_msol_account, state.treasury_msol_account);
+        assert_eq!(
+            keys.validator_list,
+            state.validator_system.validator_list.account
+        );
+        assert_eq!(keys.stake_list, state.stake_system.stake_list.account);
+        assert_eq!(
+            keys.stake_withdraw_authority,
+            find_stake_withdraw_authority(&state_addr).0
+        );
+        assert_eq!(
+            keys.stake_deposit_authority,
+            find_stake_deposit_authority(&state_addr).0
+        );
+        assert_eq!(
+            [
+                keys.burn_msol_from,
+                keys.burn_msol_authority,
+                keys.stake_account,
+                keys.split_stake_account,
+                keys.split_stake_rent_payer,
+            ],
+            accounts[..]
+        );
+        assert_eq!(keys.stake_program, stake::program::ID);
+    }
+    #[test]
+    fn stake_reserve_keys_and_args() {
+        let state_addr = Pubkey::new_unique();
+        let state = test_state(&state_addr);
+        let mut validator: ValidatorRecord = zeroed();
+        validator.validator_account = Pubkey::new_unique();
+        let (stake_account, rent_payer) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (keys, args) =
+            StakeReserveKeys::resolve(state_addr, &state, 3, &validator, stake_account, rent_payer)
+                .unwrap();
+        assert_eq!(args, StakeReserveIxArgs { validator_index: 3 });
+        assert_eq!(keys.validator_vote, validator.validator_account);
+        assert_eq!(keys.reserve_pda, find_reserve_pda(&state_addr).0);
+        assert_eq!(
+            keys.stake_deposit_authority,
+            find_stake_deposit_authority(&state_addr).0
+        );
+        assert_eq!(keys.stake_account, stake_account);
+        assert_eq!(keys.rent_payer, rent_payer);
+    }
+    #[test]
+    fn update_keys_and_args() {
+        let state_addr = Pubkey::new_unique();
+        let state = test_state(&state_addr);
+        let stake = stake_record();
+        let (active, active_args) =
+            UpdateActiveKeys::resolve(state_addr, &state, 5, &stake, 2).unwrap();
+        assert_eq!(
+            active_args,
+            UpdateActiveIxArgs {
+                stake_index: 5,
+                validator_index: 2,
+            }
+        );
+        assert_eq!(active.common_stake_account, stake.stake_account);
+        assert_eq!(
+            active.common_stake_withdraw_authority,
+            find_stake_withdraw_authority(&state_addr).0
+        );
+        assert_eq!(active.common_reserve_pda, find_reserve_pda(&state_addr).0);
+        assert_eq!(
+            active.common_msol_mint_authority,
+            find_msol_mint_authority(&state_addr).0
+        );
+        let (deactivated, deactivated_args) =
+            UpdateDeactivatedKeys::resolve(state_addr, &state, 5, &stake).unwrap();
+        assert_eq!(deactivated_args, UpdateDeactivatedIxArgs { stake_index: 5 });
+        assert_eq!(deactivated.common_reserve_pda, active.common_reserve_pda);
+        assert_eq!(
+            deactivated.operational_sol_account,
+            state.operational_sol_account
+        );
+    }
+    #[test]
+    fn deactivate_and_merge_keys_and_args() {
+        let state_addr = Pubkey::new_unique();
+        let state = test_state(&state_addr);
+        let (destination, source) = (stake_record(), stake_record());
+        let (split, payer) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (deactivate, deactivate_args) =
+            DeactivateStakeKeys::resolve(state_addr, &state, 4, &source, 1, split, payer).unwrap();
+        assert_eq!(
+            deactivate_args,
+            DeactivateStakeIxArgs {
+                stake_index: 4,
+                validator_index: 1,
+            }
+        );
+        assert_eq!(deactivate.stake_account, source.stake_account);
+        assert_eq!(
+            deactivate.stake_deposit_authority,
+            find_stake_deposit_authority(&state_addr).0
+        );
+        let (merge, merge_args) =
+            MergeStakesKeys::resolve(state_addr, &state, 7, &destination, 8, &source, 1).unwrap();
+        assert_eq!(
+            merge_args,
+            MergeStakesIxArgs {
+                destination_stake_index: 7,
+                source_stake_index: 8,
+                validator_index: 1,
+            }
+        );
+        assert_eq!(merge.destination_stake, destination.stake_account);
+        assert_eq!(merge.source_stake, source.stake_account);
+        assert_eq!(
+            merge.stake_withdraw_authority,
+            find_stake_withdraw_authority(&state_addr).0
+        );
+    }
+}
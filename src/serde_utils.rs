@@ -0,0 +1,232 @@
+pub mod pubkey {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+    use solana_program::pubkey::Pubkey;
+    use std::str::FromStr;
+    pub fn serialize<S: Serializer>(pubkey: &Pubkey, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(pubkey)
+    }
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Pubkey, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Pubkey::from_str(&s).map_err(de::Error::custom)
+    }
+}
+pub mod amount {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+    use std::fmt;
+    #[cfg(not(feature = "serde-u64-as-string"))]
+    pub fn serialize<S: Serializer>(amount: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(*amount)
+    }
+    #[cfg(feature = "serde-u64-as-string")]
+    pub fn serialize<S: Serializer>(amount: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(amount)
+    }
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(AmountVisitor)
+        } else {
+            u64::deserialize(deserializer)
+        }
+    }
+    struct AmountVisitor;
+    impl de::Visitor<'_> for AmountVisitor {
+        type Value = u64;
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a u64 as a number or a decimal string")
+        }
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<u64, E> {
+            Ok(v)
+        }
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<u64, E> {
+            u64::try_from(v).map_err(E::custom)
+        }
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<u64, E> {
+            v.parse().map_err(E::custom)
+        }
+    }
+}
+pub mod option_amount {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    #[derive(Serialize, Deserialize)]
+    struct Amount(#[serde(with = "super::amount")] u64);
+    pub fn serialize<S: Serializer>(
+        amount: &Option<u64>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        amount.map(Amount).serialize(serializer)
+    }
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<u64>, D::Error> {
+        Ok(Option::<Amount>::deserialize(deserializer)?.map(|Amount(amount)| amount))
+    }
+}
+pub mod initialize_data {
+    use crate::{Fee, InitializeData, LiqPoolInitializeData};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use solana_program::pubkey::Pubkey;
+    #[derive(Serialize, Deserialize)]
+    struct LiqPool {
+        #[serde(with = "super::amount")]
+        lp_liquidity_target: u64,
+        lp_max_fee: Fee,
+        lp_min_fee: Fee,
+        lp_treasury_cut: Fee,
+    }
+    #[derive(Serialize, Deserialize)]
+    struct Params {
+        #[serde(with = "super::pubkey")]
+        admin_authority: Pubkey,
+        #[serde(with = "super::pubkey")]
+        validator_manager_authority: Pubkey,
+        #[serde(with = "super::amount")]
+        min_stake: u64,
+        rewards_fee: Fee,
+        liq_pool: LiqPool,
+        additional_stake_record_space: u32,
+        additional_validator_record_space: u32,
+        #[serde(with = "super::amount")]
+        slots_for_stake_delta: u64,
+        #[serde(with = "super::pubkey")]
+        pause_authority: Pubkey,
+    }
+    pub fn serialize<S: Serializer>(
+        data: &InitializeData,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let liq_pool = &data.liq_pool;
+        Params {
+            admin_authority: data.admin_authority,
+            validator_manager_authority: data.validator_manager_authority,
+            min_stake: data.min_stake,
+            rewards_fee: data.rewards_fee.clone(),
+            liq_pool: LiqPool {
+                lp_liquidity_target: liq_pool.lp_liquidity_target,
+                lp_max_fee: liq_pool.lp_max_fee.clone(),
+                lp_min_fee: liq_pool.lp_min_fee.clone(),
+                lp_treasury_cut: liq_pool.lp_treasury_cut.clone(),
+            },
+            additional_stake_record_space: data.additional_stake_record_space,
+            additional_validator_record_space: data.additional_validator_record_space,
+            slots_for_stake_delta: data.slots_for_stake_delta,
+            pause_authority: data.pause_authority,
+        }
+        .serialize(serializer)
+    }
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<InitializeData, D::Error> {
+        let params = Params::deserialize(deserializer)?;
+        let liq_pool = params.liq_pool;
+        Ok(InitializeData {
+            admin_authority: params.admin_authority,
+            validator_manager_authority: params.validator_manager_authority,
+            min_stake: params.min_stake,
+            rewards_fee: params.rewards_fee,
+            liq_pool: LiqPoolInitializeData {
+                lp_liquidity_target: liq_pool.lp_liquidity_target,
+                lp_max_fee: liq_pool.lp_max_fee,
+                lp_min_fee: liq_pool.lp_min_fee,
+                lp_treasury_cut: liq_pool.lp_treasury_cut,
+            },
+            additional_stake_record_space: params.additional_stake_record_space,
+            additional_validator_record_space: params.additional_validator_record_space,
+            slots_for_stake_delta: params.slots_for_stake_delta,
+            pause_authority: params.pause_authority,
+        })
+    }
+}
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::zeroed;
+    use crate::*;
+    use solana_program::pubkey::Pubkey;
+    #[test]
+    fn initialize_event_params_use_adapters() {
+        let mut event: InitializeEvent = zeroed();
+        event.params.admin_authority = Pubkey::new_unique();
+        event.params.min_stake = 1_000_000_000;
+        event.params.liq_pool.lp_liquidity_target = 10_000_000_000_000;
+        let json = serde_json::to_value(&event).unwrap();
+        let params = &json["params"];
+        assert_eq!(
+            params["admin_authority"],
+            event.params.admin_authority.to_string()
+        );
+        assert_eq!(params["pause_authority"], Pubkey::default().to_string());
+        let (min_stake, lp_liquidity_target) = if cfg!(feature = "serde-u64-as-string") {
+            (
+                serde_json::json!("1000000000"),
+                serde_json::json!("10000000000000"),
+            )
+        } else {
+            (
+                serde_json::json!(1_000_000_000u64),
+                serde_json::json!(10_000_000_000_000u64),
+            )
+        };
+        assert_eq!(params["min_stake"], min_stake);
+        assert_eq!(
+            params["liq_pool"]["lp_liquidity_target"],
+            lp_liquidity_target
+        );
+        let parsed: InitializeEvent = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, event);
+    }
+    struct Compact(u64);
+    impl<'de> serde::Deserializer<'de> for Compact {
+        type Error = serde::de::value::Error;
+        fn deserialize_any<V: serde::de::Visitor<'de>>(
+            self,
+            _: V,
+        ) -> Result<V::Value, Self::Error> {
+            Err(serde::de::Error::custom("deserialize_any is not supported"))
+        }
+        fn deserialize_u64<V: serde::de::Visitor<'de>>(
+            self,
+            visitor: V,
+        ) -> Result<V::Value, Self::Error> {
+            visitor.visit_u64(self.0)
+        }
+        fn is_human_readable(&self) -> bool {
+            false
+        }
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u128 f32 f64 char str string
+            bytes byte_buf option unit unit_struct newtype_struct seq tuple
+            tuple_struct map struct enum identifier ignored_any
+        }
+    }
+    #[test]
+    fn amount_accepts_number_or_string_only_when_human_readable() {
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        struct Amount(#[serde(with = "crate::serde_utils::amount")] u64);
+        assert_eq!(
+            serde_json::from_str::<Amount>("1000000000").unwrap(),
+            Amount(1_000_000_000)
+        );
+        assert_eq!(
+            serde_json::from_str::<Amount>("\"1000000000\"").unwrap(),
+            Amount(1_000_000_000)
+        );
+        assert!(serde_json::from_str::<Amount>("-1").is_err());
+        assert_eq!(
+            crate::serde_utils::amount::deserialize(Compact(u64::MAX)),
+            Ok(u64::MAX)
+        );
+    }
+    #[test]
+    fn initialize_ix_args_keep_default_representation() {
+        let mut args: InitializeIxArgs = zeroed();
+        args.data.admin_authority = Pubkey::new_unique();
+        args.data.min_stake = 1_000_000_000;
+        let json = serde_json::to_value(&args).unwrap();
+        assert_eq!(
+            json["data"]["admin_authority"],
+            serde_json::to_value(args.data.admin_authority).unwrap()
+        );
+        assert_eq!(json["data"]["min_stake"], 1_000_000_000u64);
+        let parsed: InitializeIxArgs = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, args);
+    }
+}
@@ -0,0 +1,155 @@
+use crate::*;
+impl State {
+    pub fn total_cooling_down(&self) -> Result<u64, MarinadeFinanceError> {
+        self.stake_system
+            .delayed_unstake_cooling_down
+            .checked_add(self.emergency_cooling_down)
+            .ok_or(MarinadeFinanceError::CalculationFailure)
+    }
+    pub fn total_lamports_under_control(&self) -> Result<u64, MarinadeFinanceError> {
+        self.validator_system
+            .total_active_balance
+            .checked_add(self.total_cooling_down()?)
+            .and_then(|lamports| lamports.checked_add(self.available_reserve_balance))
+            .ok_or(MarinadeFinanceError::CalculationFailure)
+    }
+    pub fn total_virtual_staked_lamports(&self) -> Result<u64, MarinadeFinanceError> {
+        Ok(self
+            .total_lamports_under_control()?
+            .saturating_sub(self.circulating_ticket_balance))
+    }
+    pub fn calc_msol_from_lamports(&self, lamports: u64) -> Result<u64, MarinadeFinanceError> {
+        shares_from_value(
+            lamports,
+            self.total_virtual_staked_lamports()?,
+            self.msol_supply,
+        )
+    }
+    pub fn msol_to_sol(&self, msol_amount: u64) -> Result<u64, MarinadeFinanceError> {
+        value_from_shares(
+            msol_amount,
+            self.total_virtual_staked_lamports()?,
+            self.msol_supply,
+        )
+    }
+    pub fn calc_msol_price(&self) -> Result<u64, MarinadeFinanceError> {
+        proportional(
+            PRICE_DENOMINATOR,
+            self.total_virtual_staked_lamports()?,
+            self.msol_supply,
+        )
+    }
+    pub fn msol_price_f64(&self) -> f64 {
+        self.msol_price as f64 / PRICE_DENOMINATOR as f64
+    }
+    pub fn check_staking_cap(&self, transfering_lamports: u64) -> Result<(), MarinadeFinanceError> {
+        let result_amount = self
+            .total_lamports_under_control()?
+            .checked_add(transfering_lamports)
+            .ok_or(MarinadeFinanceError::CalculationFailure)?;
+        if result_amount > self.staking_sol_cap {
+            return Err(MarinadeFinanceError::StakingIsCapped);
+        }
+        Ok(())
+    }
+}
+impl LiqPool {
+    pub fn linear_fee(&self, lamports: u64) -> Fee {
+        if lamports >= self.lp_liquidity_target {
+            return self.lp_min_fee.clone();
+        }
+        let delta = self
+            .lp_max_fee
+            .basis_points
+            .saturating_sub(self.lp_min_fee.basis_points);
+        let reduction = delta as u128 * lamports as u128 / self.lp_liquidity_target as u128;
+        Fee {
+            basis_points: self.lp_max_fee.basis_points - reduction as u32,
+        }
+    }
+    pub fn check_lp_supply(&self, lp_mint_supply: u64) -> Result<(), MarinadeFinanceError> {
+        if lp_mint_supply > self.lp_supply {
+            return Err(MarinadeFinanceError::UnregisteredLpMinted);
+        }
+        Ok(())
+    }
+    pub fn check_liquidity_cap(
+        &self,
+        transfering_lamports: u64,
+        sol_leg_balance: u64,
+    ) -> Result<(), MarinadeFinanceError> {
+        let result_amount = sol_leg_balance
+            .checked_add(transfering_lamports)
+            .ok_or(MarinadeFinanceError::CalculationFailure)?;
+        if result_amount > self.liquidity_sol_cap {
+            return Err(MarinadeFinanceError::LiquidityIsCapped);
+        }
+        Ok(())
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{test_liq_pool, zeroed};
+    fn mainnet_like_state() -> State {
+        let mut state: State = zeroed();
+        state.validator_system.total_active_balance = 7_123_456_789_012_345;
+        state.stake_system.delayed_unstake_cooling_down = 12_345_678_901;
+        state.available_reserve_balance = 45_678_901_234_567;
+        state.circulating_ticket_balance = 9_876_543_210;
+        state.msol_supply = 5_678_901_234_567_890;
+        state
+    }
+    #[test]
+    fn mainnet_like_price() {
+        let state = mainnet_like_state();
+        assert_eq!(
+            state.total_virtual_staked_lamports().unwrap(),
+            7_169_138_159_382_603
+        );
+        assert_eq!(state.calc_msol_price().unwrap(), 5_422_037_232);
+        assert_eq!(
+            state.calc_msol_from_lamports(1_000_000_000).unwrap(),
+            792_131_649
+        );
+        assert_eq!(state.msol_to_sol(1_000_000_000).unwrap(), 1_262_416_418);
+    }
+    #[test]
+    fn empty_msol_supply_is_one_to_one() {
+        let mut state = mainnet_like_state();
+        state.msol_supply = 0;
+        assert_eq!(state.calc_msol_from_lamports(1_234_567).unwrap(), 1_234_567);
+        assert_eq!(state.msol_to_sol(1_234_567).unwrap(), 1_234_567);
+        assert_eq!(state.calc_msol_price().unwrap(), PRICE_DENOMINATOR);
+    }
+    #[test]
+    fn tickets_exceeding_lamports_saturate() {
+        let mut state = mainnet_like_state();
+        state.circulating_ticket_balance = state.total_lamports_under_control().unwrap() + 1;
+        assert_eq!(state.total_virtual_staked_lamports().unwrap(), 0);
+        assert_eq!(state.msol_to_sol(1_000_000_000).unwrap(), 0);
+        assert_eq!(state.calc_msol_price().unwrap(), 0);
+        assert_eq!(
+            state.calc_msol_from_lamports(1_000_000_000).unwrap(),
+            1_000_000_000
+        );
+    }
+    #[test]
+    fn linear_fee_endpoints() {
+        let liq_pool = test_liq_pool();
+        assert_eq!(liq_pool.linear_fee(0), Fee::from_basis_points(300));
+        assert_eq!(
+            liq_pool.linear_fee(5_000_000_000_000),
+            Fee::from_basis_points(165)
+        );
+        assert_eq!(
+            liq_pool.linear_fee(9_999_999_999_999),
+            Fee::from_basis_points(31)
+        );
+        assert_eq!(
+            liq_pool.linear_fee(10_000_000_000_000),
+            Fee::from_basis_points(30)
+        );
+        assert_eq!(liq_pool.linear_fee(u64::MAX), Fee::from_basis_points(30));
+    }
+}
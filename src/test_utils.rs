@@ -0,0 +1,51 @@
+use crate::*;
+use borsh::BorshDeserialize;
+use solana_program::pubkey::Pubkey;
+pub(crate) fn zeroed<T: BorshDeserialize>() -> T {
+    T::deserialize(&mut &[0u8; 2048][..]).unwrap()
+}
+pub(crate) fn test_state(state_addr: &Pubkey) -> State {
+    let mut state: State = zeroed();
+    state.msol_mint = Pubkey::new_unique();
+    state.admin_authority = Pubkey::new_unique();
+    state.operational_sol_account = Pubkey::new_unique();
+    state.treasury_msol_account = Pubkey::new_unique();
+    state.reserve_bump_seed = find_reserve_pda(state_addr).1;
+    state.msol_mint_authority_bump_seed = find_msol_mint_authority(state_addr).1;
+    state.stake_system.stake_list.account = Pubkey::new_unique();
+    state.stake_system.stake_deposit_bump_seed = find_stake_deposit_authority(state_addr).1;
+    state.stake_system.stake_withdraw_bump_seed = find_stake_withdraw_authority(state_addr).1;
+    state.validator_system.validator_list.account = Pubkey::new_unique();
+    state.liq_pool.lp_mint = Pubkey::new_unique();
+    state.liq_pool.msol_leg = Pubkey::new_unique();
+    state.liq_pool.lp_mint_authority_bump_seed = find_lp_mint_authority(state_addr).1;
+    state.liq_pool.sol_leg_bump_seed = find_liq_pool_sol_leg_pda(state_addr).1;
+    state.liq_pool.msol_leg_authority_bump_seed = find_liq_pool_msol_leg_authority(state_addr).1;
+    state.pause_authority = Pubkey::new_unique();
+    state
+}
+pub(crate) fn patterned<T: BorshDeserialize>() -> T {
+    let bytes: Vec<u8> = (0..2048u32).map(|i| (i * 7 % 251 + 1) as u8).collect();
+    T::deserialize(&mut bytes.as_slice()).unwrap()
+}
+pub(crate) const TEST_RENT_EXEMPT: u64 = 2_039_280;
+pub(crate) fn test_liq_pool() -> LiqPool {
+    let mut liq_pool: LiqPool = zeroed();
+    liq_pool.lp_liquidity_target = 10_000_000_000_000;
+    liq_pool.lp_max_fee = Fee::from_basis_points(300);
+    liq_pool.lp_min_fee = Fee::from_basis_points(30);
+    liq_pool.treasury_cut = Fee::from_basis_points(2_500);
+    liq_pool.liquidity_sol_cap = u64::MAX;
+    liq_pool
+}
+pub(crate) fn quote_state() -> State {
+    let mut state: State = zeroed();
+    state.validator_system.total_active_balance = 1_250_000_000_000;
+    state.msol_supply = 1_000_000_000_000;
+    state.rent_exempt_for_token_acc = TEST_RENT_EXEMPT;
+    state.min_deposit = 1;
+    state.min_withdraw = 1;
+    state.staking_sol_cap = u64::MAX;
+    state.liq_pool = test_liq_pool();
+    state
+}
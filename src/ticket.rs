@@ -0,0 +1,118 @@
+use crate::*;
+use solana_program::{
+    clock::{Clock, UnixTimestamp, DEFAULT_MS_PER_SLOT},
+    epoch_schedule::EpochSchedule,
+};
+pub const TICKET_EXTRA_WAIT_SECONDS: i64 = 30 * 60;
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TicketStatus {
+    Pending,
+    DueNotReady,
+    Claimable,
+}
+impl TicketStatus {
+    pub fn check(self) -> Result<(), MarinadeFinanceError> {
+        match self {
+            Self::Pending => Err(MarinadeFinanceError::TicketNotDue),
+            Self::DueNotReady => Err(MarinadeFinanceError::TicketNotReady),
+            Self::Claimable => Ok(()),
+        }
+    }
+}
+impl TicketAccountData {
+    pub fn due_epoch(&self) -> u64 {
+        self.created_epoch.saturating_add(1)
+    }
+    pub fn status(&self, clock: &Clock) -> TicketStatus {
+        let due_epoch = self.due_epoch();
+        if clock.epoch < due_epoch {
+            TicketStatus::Pending
+        } else if clock.epoch == due_epoch
+            && clock.unix_timestamp - clock.epoch_start_timestamp < TICKET_EXTRA_WAIT_SECONDS
+        {
+            TicketStatus::DueNotReady
+        } else {
+            TicketStatus::Claimable
+        }
+    }
+    pub fn estimated_claimable_timestamp(
+        &self,
+        clock: &Clock,
+        epoch_schedule: &EpochSchedule,
+    ) -> UnixTimestamp {
+        match self.status(clock) {
+            TicketStatus::Claimable => clock.unix_timestamp,
+            TicketStatus::DueNotReady => clock.epoch_start_timestamp + TICKET_EXTRA_WAIT_SECONDS,
+            TicketStatus::Pending => {
+                let remaining_slots = epoch_schedule
+                    .get_first_slot_in_epoch(self.due_epoch())
+                    .saturating_sub(clock.slot);
+                let remaining_seconds = remaining_slots.saturating_mul(DEFAULT_MS_PER_SLOT) / 1000;
+                clock
+                    .unix_timestamp
+                    .saturating_add(remaining_seconds as i64)
+                    .saturating_add(TICKET_EXTRA_WAIT_SECONDS)
+            }
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::zeroed;
+    const EPOCH_START: UnixTimestamp = 1_700_000_000;
+    fn ticket() -> TicketAccountData {
+        let mut ticket: TicketAccountData = zeroed();
+        ticket.created_epoch = 10;
+        ticket
+    }
+    fn clock(epoch: u64, seconds_into_epoch: i64) -> Clock {
+        Clock {
+            slot: epoch * 432_000,
+            epoch_start_timestamp: EPOCH_START,
+            epoch,
+            leader_schedule_epoch: epoch + 1,
+            unix_timestamp: EPOCH_START + seconds_into_epoch,
+        }
+    }
+    #[test]
+    fn pending_until_due_epoch() {
+        let status = ticket().status(&clock(10, 100_000));
+        assert_eq!(status, TicketStatus::Pending);
+        assert_eq!(status.check(), Err(MarinadeFinanceError::TicketNotDue));
+    }
+    #[test]
+    fn extra_wait_boundary() {
+        let ticket = ticket();
+        let status = ticket.status(&clock(11, TICKET_EXTRA_WAIT_SECONDS - 1));
+        assert_eq!(status, TicketStatus::DueNotReady);
+        assert_eq!(status.check(), Err(MarinadeFinanceError::TicketNotReady));
+        let status = ticket.status(&clock(11, TICKET_EXTRA_WAIT_SECONDS));
+        assert_eq!(status, TicketStatus::Claimable);
+        assert_eq!(status.check(), Ok(()));
+        assert_eq!(ticket.status(&clock(12, 0)), TicketStatus::Claimable);
+    }
+    #[test]
+    fn estimated_claimable_timestamp() {
+        let ticket = ticket();
+        let epoch_schedule = EpochSchedule::without_warmup();
+        let mut pending = clock(10, 0);
+        pending.slot = epoch_schedule.get_first_slot_in_epoch(11) - 1_000;
+        assert_eq!(
+            ticket.estimated_claimable_timestamp(&pending, &epoch_schedule),
+            pending.unix_timestamp
+                + (1_000 * DEFAULT_MS_PER_SLOT / 1_000) as i64
+                + TICKET_EXTRA_WAIT_SECONDS
+        );
+        let due = clock(11, 60);
+        assert_eq!(
+            ticket.estimated_claimable_timestamp(&due, &epoch_schedule),
+            EPOCH_START + TICKET_EXTRA_WAIT_SECONDS
+        );
+        let claimable = clock(11, TICKET_EXTRA_WAIT_SECONDS + 5);
+        assert_eq!(
+            ticket.estimated_claimable_timestamp(&claimable, &epoch_schedule),
+            claimable.unix_timestamp
+        );
+    }
+}
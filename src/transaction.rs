@@ -0,0 +1,128 @@
+use crate::*;
+use solana_program::{
+    instruction::CompiledInstruction, message::v0::LoadedAddresses, pubkey::Pubkey,
+};
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InnerInstructions {
+    pub index: u8,
+    pub instructions: Vec<CompiledInstruction>,
+}
+#[derive(Debug)]
+pub struct DecodedMarinadeFinanceIx {
+    pub instruction_index: u8,
+    pub inner_instruction_index: Option<usize>,
+    pub ix: std::io::Result<MarinadeFinanceProgramIxWithKeys>,
+}
+pub fn decode_marinade_finance_ixs(
+    static_account_keys: &[Pubkey],
+    loaded_addresses: &LoadedAddresses,
+    instructions: &[CompiledInstruction],
+    inner_instructions: &[InnerInstructions],
+) -> Vec<DecodedMarinadeFinanceIx> {
+    let account_keys: Vec<Pubkey> = static_account_keys
+        .iter()
+        .chain(&loaded_addresses.writable)
+        .chain(&loaded_addresses.readonly)
+        .copied()
+        .collect();
+    let is_marinade_ix = |ix: &CompiledInstruction| {
+        account_keys.get(ix.program_id_index as usize) == Some(&crate::ID)
+    };
+    let mut decoded = Vec::new();
+    for (instruction_index, ix) in (0..=u8::MAX).zip(instructions) {
+        if is_marinade_ix(ix) {
+            decoded.push(DecodedMarinadeFinanceIx {
+                instruction_index,
+                inner_instruction_index: None,
+                ix: MarinadeFinanceProgramIxWithKeys::from_compiled_instruction(ix, &account_keys),
+            });
+        }
+        for inner in inner_instructions
+            .iter()
+            .filter(|inner| inner.index == instruction_index)
+        {
+            for (inner_instruction_index, inner_ix) in inner.instructions.iter().enumerate() {
+                if !is_marinade_ix(inner_ix) {
+                    continue;
+                }
+                decoded.push(DecodedMarinadeFinanceIx {
+                    instruction_index,
+                    inner_instruction_index: Some(inner_instruction_index),
+                    ix: MarinadeFinanceProgramIxWithKeys::from_compiled_instruction(
+                        inner_ix,
+                        &account_keys,
+                    ),
+                });
+            }
+        }
+    }
+    decoded
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn undecodable_ix_does_not_hide_others() {
+        let lookup_key = Pubkey::new_unique();
+        let static_account_keys = [Pubkey::new_unique(), crate::ID];
+        let loaded_addresses = LoadedAddresses {
+            writable: vec![lookup_key],
+            readonly: vec![],
+        };
+        let claim = CompiledInstruction {
+            program_id_index: 1,
+            accounts: vec![2; CLAIM_IX_ACCOUNTS_LEN],
+            data: CLAIM_IX_DISCM.to_vec(),
+        };
+        let unknown = CompiledInstruction {
+            program_id_index: 1,
+            accounts: vec![],
+            data: vec![0; 8],
+        };
+        let other_program = CompiledInstruction {
+            program_id_index: 0,
+            accounts: vec![],
+            data: vec![],
+        };
+        let inner_instructions = [InnerInstructions {
+            index: 1,
+            instructions: vec![claim.clone()],
+        }];
+        let decoded = decode_marinade_finance_ixs(
+            &static_account_keys,
+            &loaded_addresses,
+            &[claim, unknown, other_program],
+            &inner_instructions,
+        );
+        assert_eq!(decoded.len(), 3);
+        assert_eq!(
+            (
+                decoded[0].instruction_index,
+                decoded[0].inner_instruction_index
+            ),
+            (0, None)
+        );
+        match decoded[0].ix.as_ref().unwrap() {
+            MarinadeFinanceProgramIxWithKeys::Claim(keys) => {
+                assert_eq!(keys.ticket_account, lookup_key)
+            }
+            ix => panic!("unexpected instruction {:?}", ix),
+        }
+        assert_eq!(
+            (
+                decoded[1].instruction_index,
+                decoded[1].inner_instruction_index
+            ),
+            (1, None)
+        );
+        assert!(decoded[1].ix.is_err());
+        assert_eq!(
+            (
+                decoded[2].instruction_index,
+                decoded[2].inner_instruction_index
+            ),
+            (1, Some(0))
+        );
+        assert!(decoded[2].ix.is_ok());
+    }
+}
@@ -3,13 +3,16 @@ use solana_program::pubkey::Pubkey;
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SplitStakeAccountInfo {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
     pub account: Pubkey,
     pub index: u32,
 }
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct U64ValueChange {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
     pub old: u64,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::amount"))]
     pub new: u64,
 }
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
@@ -33,7 +36,9 @@ pub struct FeeCentsValueChange {
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PubkeyValueChange {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
     pub old: Pubkey,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_utils::pubkey"))]
     pub new: Pubkey,
 }
 #[derive(Clone, Debug, BorshDeserialize, BorshSerialize, PartialEq)]